@@ -0,0 +1,178 @@
+use std::fmt;
+
+/// Heap allocation statistics recorded while running a single phase of a problem.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations (including reallocations) made.
+    pub allocations: u64,
+    /// Total number of bytes requested across all allocations.
+    pub bytes_allocated: u64,
+    /// Peak number of heap bytes in use by live allocations, above the number in use when the
+    /// phase started. This is not the peak resident memory of the process, which also includes
+    /// the stack, the program itself and memory held by the system allocator.
+    pub peak_heap_bytes: u64,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak heap",
+            self.allocations,
+            format_bytes(self.bytes_allocated),
+            format_bytes(self.peak_heap_bytes)
+        )
+    }
+}
+
+/// Formats the number of bytes using binary units, e.g. "1.50 KiB".
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.2} {}", UNITS[unit])
+}
+
+/// Runs the function, returning its result and the heap allocation statistics for the current
+/// thread while it was running. The statistics are None unless the `alloc-stats` feature is
+/// enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let (result, stats) = counting::measure(f);
+        (result, Some(stats))
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), None)
+    }
+}
+
+/// Counting global allocator, installed when the `alloc-stats` feature is enabled.
+///
+/// Allocations are counted for the thread that makes them, so phases solved at the same time on
+/// other threads do not affect each other's statistics.
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    use super::AllocStats;
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+        static BYTES_ALLOCATED: Cell<u64> = const { Cell::new(0) };
+        static CURRENT_BYTES: Cell<i64> = const { Cell::new(0) };
+        static PEAK_BYTES: Cell<i64> = const { Cell::new(0) };
+    }
+
+    /// Global allocator that records the allocations made by each thread before passing them on
+    /// to the system allocator.
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record_dealloc(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                record_dealloc(layout.size());
+                record_alloc(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    /// Records an allocation of the given size for the current thread. Counters are skipped if
+    /// the thread-local storage has already been torn down.
+    fn record_alloc(size: usize) {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        let _ = BYTES_ALLOCATED.try_with(|n| n.set(n.get() + size as u64));
+        let _ = CURRENT_BYTES.try_with(|current| {
+            current.set(current.get() + size as i64);
+            let _ = PEAK_BYTES.try_with(|peak| peak.set(peak.get().max(current.get())));
+        });
+    }
+
+    /// Records a deallocation of the given size for the current thread.
+    fn record_dealloc(size: usize) {
+        let _ = CURRENT_BYTES.try_with(|current| current.set(current.get() - size as i64));
+    }
+
+    /// Runs the function, returning its result and the allocation statistics for the current
+    /// thread while it was running.
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+        let start_allocations = ALLOCATIONS.get();
+        let start_bytes = BYTES_ALLOCATED.get();
+        let start_current = CURRENT_BYTES.get();
+        // Track the peak from the current usage, restoring the outer peak afterwards
+        let outer_peak = PEAK_BYTES.replace(start_current);
+        let result = f();
+        let peak = PEAK_BYTES.replace(outer_peak.max(PEAK_BYTES.get()));
+        let stats = AllocStats {
+            allocations: ALLOCATIONS.get() - start_allocations,
+            bytes_allocated: BYTES_ALLOCATED.get() - start_bytes,
+            peak_heap_bytes: (peak - start_current).max(0) as u64,
+        };
+        (result, stats)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that byte counts are formatted using binary units.
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!("512 B", format_bytes(512));
+        assert_eq!("1.50 KiB", format_bytes(1536));
+        assert_eq!("3.00 MiB", format_bytes(3 * 1024 * 1024));
+    }
+
+    /// Tests that the allocations made by the measured function are counted.
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn test_measure_allocations() {
+        let (len, stats) = measure(|| {
+            let mut values: Vec<u64> = Vec::with_capacity(1000);
+            values.extend(0..1000);
+            let copy = values.clone();
+            copy.len()
+        });
+        let stats = stats.unwrap();
+        assert_eq!(1000, len);
+        assert_eq!(2, stats.allocations);
+        assert_eq!(16000, stats.bytes_allocated);
+        assert_eq!(16000, stats.peak_heap_bytes);
+    }
+}
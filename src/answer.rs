@@ -0,0 +1,183 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Solution to one part of a problem.
+///
+/// Integer answers are equal if they have the same value, regardless of the variant holding them,
+/// so an answer of `Unsigned(5)` is equal to `Signed(5)`. Integer answers are also ordered by
+/// value, while text answers are not ordered.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    BigSigned(i128),
+    BigUnsigned(u128),
+    Text(String),
+}
+
+impl Answer {
+    /// Parses the answer from text, using the smallest integer variant that can hold the value.
+    /// Text that is not an integer is kept as a text answer.
+    pub fn parse(s: &str) -> Self {
+        let s = s.trim();
+        if let Ok(n) = s.parse::<u64>() {
+            Answer::Unsigned(n)
+        } else if let Ok(n) = s.parse::<i64>() {
+            Answer::Signed(n)
+        } else if let Ok(n) = s.parse::<u128>() {
+            Answer::BigUnsigned(n)
+        } else if let Ok(n) = s.parse::<i128>() {
+            Answer::BigSigned(n)
+        } else {
+            Answer::Text(s.to_string())
+        }
+    }
+
+    /// Gets the name of the type holding the answer, e.g. "u64".
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Signed(_) => "i64",
+            Answer::Unsigned(_) => "u64",
+            Answer::BigSigned(_) => "i128",
+            Answer::BigUnsigned(_) => "u128",
+            Answer::Text(_) => "text",
+        }
+    }
+
+    /// Checks if the answer matches the expected answer given as text, e.g. from an answers file.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Text(s) => s == expected.trim(),
+            _ => *self == Answer::parse(expected),
+        }
+    }
+
+    /// Gets the sign and magnitude of an integer answer, or None for a text answer.
+    fn integer(&self) -> Option<(bool, u128)> {
+        match self {
+            Answer::Signed(n) => Some((*n < 0, n.unsigned_abs() as u128)),
+            Answer::Unsigned(n) => Some((false, *n as u128)),
+            Answer::BigSigned(n) => Some((*n < 0, n.unsigned_abs())),
+            Answer::BigUnsigned(n) => Some((false, *n)),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::BigSigned(n) => write!(f, "{n}"),
+            Answer::BigUnsigned(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => self.integer().is_some() && self.integer() == other.integer(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.integer(), other.integer()) {
+            (Some((a_negative, a)), Some((b_negative, b))) => match (a_negative, b_negative) {
+                (false, false) => Some(a.cmp(&b)),
+                (true, true) => Some(b.cmp(&a)),
+                (true, false) => Some(Ordering::Less),
+                (false, true) => Some(Ordering::Greater),
+            },
+            _ => (self == other).then_some(Ordering::Equal),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other)
+    }
+}
+
+/// Implements conversion into an [`Answer`] and equality with an [`Answer`] for the given types,
+/// converting each to the given variant.
+macro_rules! impl_answer_from {
+    ($variant:ident($inner:ty): $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $inner)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    self.integer() == Answer::$variant(*other as $inner).integer()
+                }
+            }
+        )+
+    };
+}
+
+impl_answer_from!(Signed(i64): i8, i16, i32, i64, isize);
+impl_answer_from!(Unsigned(u64): u8, u16, u32, u64, usize);
+impl_answer_from!(BigSigned(i128): i128);
+impl_answer_from!(BigUnsigned(u128): u128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that answers are parsed into the smallest integer variant that can hold the value.
+    #[test]
+    fn test_answer_parse() {
+        assert_eq!("u64", Answer::parse("35").kind());
+        assert_eq!("i64", Answer::parse("-12").kind());
+        assert_eq!("u128", Answer::parse("18446744073709551616").kind());
+        assert_eq!("i128", Answer::parse("-9223372036854775809").kind());
+        assert_eq!("text", Answer::parse("ABC-12").kind());
+    }
+
+    /// Tests that integer answers are compared by value across variants, and text answers by
+    /// content.
+    #[test]
+    fn test_answer_eq() {
+        assert_eq!(Answer::Unsigned(288), Answer::Signed(288));
+        assert_eq!(Answer::BigUnsigned(288), 288usize);
+        assert_ne!(Answer::Signed(-1), Answer::BigUnsigned(u128::MAX));
+        assert_ne!(Answer::Unsigned(288), Answer::from("288"));
+        assert_eq!(Answer::from("LKJ"), "LKJ");
+        assert_eq!(Answer::from("288"), "288");
+        assert!(Answer::from(71503u32).matches("71503"));
+    }
+
+    /// Tests that integer answers are ordered by value and text answers are not ordered.
+    #[test]
+    fn test_answer_ord() {
+        assert!(Answer::Unsigned(288) < Answer::BigSigned(289));
+        assert!(Answer::Signed(-5) < Answer::Signed(-4));
+        assert!(Answer::BigSigned(i128::MIN) < Answer::Unsigned(0));
+        assert_eq!(None, Answer::from("LKJ").partial_cmp(&Answer::Unsigned(1)));
+        assert_eq!(None, Answer::from("LKJ").partial_cmp(&Answer::from("ABC")));
+    }
+}
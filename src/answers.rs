@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::path::Path;
+
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::OsRng;
+use sha2::{Digest, Sha256};
+
+use crate::utils::{hex, input};
+use crate::{Answer, ParseError};
+
+/// Environment variable giving the location of the answers file to use instead of the default.
+pub const ANSWERS_FILE_ENV_VAR: &str = "AOC_ANSWERS_FILE";
+
+/// Default location of the answers file, relative to the crate root.
+pub const DEFAULT_ANSWERS_FILE: &str = "./answers.txt";
+
+/// Prefix of a hashed answer in the answers file, which is in the format "sha256:<salt>:<hash>".
+pub const HASHED_ANSWER_PREFIX: &str = "sha256:";
+
+/// Length in bytes of the random salt used when hashing an answer.
+const SALT_LEN: usize = 16;
+
+/// Expected answer recorded in the answer registry.
+///
+/// Hashed answers store the SHA-256 hash of a random salt followed by the answer, so that the
+/// answers file can be published without revealing the answers. The [`fmt::Display`]
+/// implementation never reveals a hashed answer.
+///
+/// The salt is stored next to the hash and most answers are small integers, so a hashed answer
+/// can be recovered by hashing each candidate answer in turn. Hashing only keeps answers from
+/// being read directly from the file, and does not protect them from a determined reader.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpectedAnswer {
+    Plain(String),
+    Hashed { salt: Vec<u8>, hash: Vec<u8> },
+}
+
+impl ExpectedAnswer {
+    /// Parses the expected answer from the answers file. Answers starting with "sha256:" are
+    /// hashed answers, and must be in the format "sha256:<salt>:<hash>" using hex digits.
+    ///
+    /// Returns None if a hashed answer is not in the expected format.
+    pub fn parse(s: &str) -> Option<Self> {
+        let Some(hashed) = s.strip_prefix(HASHED_ANSWER_PREFIX) else {
+            return Some(Self::Plain(s.to_string()));
+        };
+        let (salt, hash) = hashed.split_once(':')?;
+        let salt = hex::decode(salt).filter(|salt| !salt.is_empty())?;
+        let hash = hex::decode(hash).filter(|hash| hash.len() == Sha256::output_size())?;
+        Some(Self::Hashed { salt, hash })
+    }
+
+    /// Hashes the answer with a new random salt.
+    pub fn hash(answer: &str) -> Self {
+        let mut salt = vec![0; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let hash = hash_answer(&salt, answer);
+        Self::Hashed { salt, hash }
+    }
+
+    /// Formats the expected answer in the format used by the answers file.
+    pub fn to_record(&self) -> String {
+        match self {
+            Self::Plain(answer) => answer.to_string(),
+            Self::Hashed { salt, hash } => format!(
+                "{HASHED_ANSWER_PREFIX}{}:{}",
+                hex::encode(salt),
+                hex::encode(hash)
+            ),
+        }
+    }
+
+    /// Checks if the answer matches the expected answer.
+    pub fn matches(&self, answer: &Answer) -> bool {
+        match self {
+            Self::Plain(expected) => answer.matches(expected),
+            Self::Hashed { salt, hash } => hash_answer(salt, &answer.to_string()) == *hash,
+        }
+    }
+}
+
+impl fmt::Display for ExpectedAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Plain(answer) => write!(f, "{answer}"),
+            Self::Hashed { .. } => write!(f, "hashed answer"),
+        }
+    }
+}
+
+/// Result of verifying an answer against the answer registry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    /// No answer has been recorded for the day, part and input file.
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Hashes the salt followed by the answer. Integer answers are hashed in their canonical form, so
+/// the hash does not depend on the integer type used by a solver (see [`Answer::matches`]).
+fn hash_answer(salt: &[u8], answer: &str) -> Vec<u8> {
+    Sha256::new()
+        .chain_update(salt)
+        .chain_update(Answer::parse(answer).to_string())
+        .finalize()
+        .to_vec()
+}
+
+/// Returns the name of the answers file given by the `AOC_ANSWERS_FILE` environment variable, or
+/// the default answers file if the variable is not set.
+pub fn answers_file() -> String {
+    env::var(ANSWERS_FILE_ENV_VAR)
+        .ok()
+        .filter(|filename| !filename.is_empty())
+        .unwrap_or_else(|| DEFAULT_ANSWERS_FILE.to_string())
+}
+
+/// Registry of the expected answers for each day, part and input file.
+///
+/// Answers are loaded from a text file where each non-empty line (other than comments starting
+/// with '#') is in the format "<day> <part> <input file> <answer>". Input files are given relative
+/// to the crate root, so the same registry can hold the answers for several people's inputs.
+#[derive(Debug, Default)]
+pub struct AnswerRegistry {
+    answers: HashMap<(u64, u8, String), ExpectedAnswer>,
+}
+
+impl AnswerRegistry {
+    /// Loads the answer registry from the file named by the `AOC_ANSWERS_FILE` environment
+    /// variable, or the default answers file if the variable is not set.
+    pub fn load() -> Result<Self, ParseError> {
+        Self::load_from(&answers_file())
+    }
+
+    /// Loads the answer registry from the given file. Relative paths are resolved from the crate
+    /// root.
+    pub fn load_from(filename: &str) -> Result<Self, ParseError> {
+        let raw_input = input::read_input(filename)?;
+        Self::parse(filename, &raw_input)
+    }
+
+    /// Parses the contents of an answers file into an answer registry.
+    pub(crate) fn parse(filename: &str, raw_input: &str) -> Result<Self, ParseError> {
+        let mut answers: HashMap<(u64, u8, String), ExpectedAnswer> = HashMap::new();
+        for (i, line) in raw_input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            let day = fields.first().and_then(|s| s.parse::<u64>().ok());
+            let part = fields
+                .get(1)
+                .and_then(|s| s.parse::<u8>().ok())
+                .filter(|part| (1..=2).contains(part));
+            match (day, part, fields.get(2), fields.get(3..)) {
+                (Some(day), Some(part), Some(input_file), Some(answer)) if !answer.is_empty() => {
+                    let Some(answer) = ExpectedAnswer::parse(&answer.join(" ")) else {
+                        return Err(ParseError::unexpected_at(
+                            filename,
+                            i + 1,
+                            line,
+                            line.find(HASHED_ANSWER_PREFIX).unwrap_or_default(),
+                            "hashed answer in format \"sha256:<salt>:<hash>\"",
+                        ));
+                    };
+                    let key = (day, part, normalise_input_file(input_file));
+                    answers.insert(key, answer);
+                }
+                _ => {
+                    return Err(ParseError::unexpected(
+                        filename,
+                        i + 1,
+                        1,
+                        "answer in format \"<day> <part> <input file> <answer>\"",
+                    ))
+                }
+            }
+        }
+        Ok(Self { answers })
+    }
+
+    /// Gets the expected answer for the given day, part and input file, if one is recorded.
+    pub fn expected(&self, day: u64, part: u8, input_file: &str) -> Option<&ExpectedAnswer> {
+        self.answers
+            .get(&(day, part, normalise_input_file(input_file)))
+    }
+
+    /// Verifies the answer for the given day, part and input file against the expected answer,
+    /// without revealing the expected answer.
+    pub fn verify(&self, day: u64, part: u8, input_file: &str, answer: &Answer) -> Verdict {
+        match self.expected(day, part, input_file) {
+            Some(expected) if expected.matches(answer) => Verdict::Correct,
+            Some(_) => Verdict::Wrong,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// Replaces each plain text answer in the contents of an answers file with a salted hash of the
+/// answer, leaving comments and answers that are already hashed unchanged.
+///
+/// Returns the new contents of the answers file and the number of answers hashed, or an error if
+/// the answers file is malformed.
+pub fn hash_answers(filename: &str, raw_input: &str) -> Result<(String, usize), ParseError> {
+    // Check the whole file is valid before rewriting any lines
+    AnswerRegistry::parse(filename, raw_input)?;
+    let mut output = String::new();
+    let mut hashed = 0;
+    for line in raw_input.lines() {
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        match ExpectedAnswer::parse(&fields.get(3..).unwrap_or_default().join(" ")) {
+            Some(ExpectedAnswer::Plain(answer))
+                if !line.trim().starts_with('#') && fields.len() > 3 =>
+            {
+                let answer = ExpectedAnswer::hash(&answer);
+                output.push_str(&format!("{} {}", fields[..3].join(" "), answer.to_record()));
+                hashed += 1;
+            }
+            _ => output.push_str(line),
+        }
+        output.push('\n');
+    }
+    Ok((output, hashed))
+}
+
+/// Normalises the input file path into the form used as a key in the answer registry. Paths under
+/// the crate root are made relative to the crate root, without a leading "./".
+pub fn normalise_input_file(filename: &str) -> String {
+    let path = Path::new(filename);
+    let path = path
+        .strip_prefix(env!("CARGO_MANIFEST_DIR"))
+        .unwrap_or(path);
+    let path = path.strip_prefix(".").unwrap_or(path);
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Asserts that the solution matches the expected answer recorded in the answer registry for the
+/// given day, part and input file. Hashed answers are not revealed if the solution is wrong. If no
+/// answer has been recorded (e.g. for a new day), the check is skipped with a message on stderr.
+///
+/// Panics if the solution does not match.
+pub fn assert_answer(day: u64, part: u8, input_file: &str, solution: impl Into<Answer>) {
+    let solution = solution.into();
+    let registry = AnswerRegistry::load().unwrap_or_else(|err| panic!("{err}"));
+    let Some(expected) = registry.expected(day, part, input_file) else {
+        eprintln!(
+            "[*] Skipping check, no answer recorded for Day {day:02} Part {part} with input file \
+             \"{}\"",
+            normalise_input_file(input_file)
+        );
+        return;
+    };
+    assert!(
+        expected.matches(&solution),
+        "wrong answer for Day {day:02} Part {part} with input file \"{}\": expected {expected}, \
+         got {solution}",
+        normalise_input_file(input_file)
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that answers are looked up by day, part and normalised input file.
+    #[test]
+    fn test_answer_registry_parse() {
+        let raw_input = "# comment\n\n5 1 input/day05.txt 35\n5 2 ./input/alice/day05.txt 46\n";
+        let registry = AnswerRegistry::parse("answers.txt", raw_input).unwrap();
+        let expected = |part, input_file| {
+            registry
+                .expected(5, part, input_file)
+                .map(|answer| answer.to_string())
+        };
+        assert_eq!(Some(String::from("35")), expected(1, "./input/day05.txt"));
+        assert_eq!(
+            Some(String::from("46")),
+            expected(2, "input/alice/day05.txt")
+        );
+        assert_eq!(None, expected(2, "./input/day05.txt"));
+    }
+
+    /// Tests that hashed answers are verified without revealing the expected answer.
+    #[test]
+    fn test_answer_registry_verify_hashed() {
+        let (raw_input, hashed) =
+            hash_answers("answers.txt", "# comment\n4 2 input/day04.txt 30\n").unwrap();
+        assert_eq!(1, hashed);
+        assert!(raw_input.starts_with("# comment\n4 2 input/day04.txt sha256:"));
+        assert!(!raw_input.ends_with(" 30\n"));
+        let registry = AnswerRegistry::parse("answers.txt", &raw_input).unwrap();
+        let verify =
+            |part, answer: u64| registry.verify(4, part, "input/day04.txt", &answer.into());
+        assert_eq!(Verdict::Correct, verify(2, 30));
+        assert_eq!(Verdict::Wrong, verify(2, 31));
+        assert_eq!(Verdict::Unknown, verify(1, 13));
+        let expected = registry.expected(4, 2, "input/day04.txt").unwrap();
+        assert_eq!("hashed answer", expected.to_string());
+        // Hashing an answers file that is already hashed leaves it unchanged
+        assert_eq!(
+            (raw_input.clone(), 0),
+            hash_answers("answers.txt", &raw_input).unwrap()
+        );
+    }
+
+    /// Tests that a malformed line in the answers file is reported as a parse error.
+    #[test]
+    fn test_answer_registry_parse_malformed() {
+        let err = AnswerRegistry::parse("answers.txt", "5 1 input/day05.txt 35\n5 3 x 1\n");
+        assert_eq!(
+            "answers.txt:2:1: expected answer in format \"<day> <part> <input file> <answer>\"",
+            err.unwrap_err().to_string()
+        );
+    }
+
+    /// Tests that input files under the crate root are normalised to relative paths.
+    #[test]
+    fn test_normalise_input_file() {
+        let absolute = format!("{}/input/day05.txt", env!("CARGO_MANIFEST_DIR"));
+        assert_eq!("input/day05.txt", normalise_input_file(&absolute));
+        assert_eq!("input/day05.txt", normalise_input_file("./input/day05.txt"));
+        assert_eq!("/tmp/day05.txt", normalise_input_file("/tmp/day05.txt"));
+    }
+}
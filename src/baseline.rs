@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::time::Duration;
+
+use crate::runner::{DayReport, Phase};
+use crate::utils::input;
+use crate::ParseError;
+
+/// Default percentage by which a phase must be slower than the baseline to count as a regression.
+pub const DEFAULT_THRESHOLD_PERCENT: f64 = 10.0;
+
+/// Saved execution times for each day and phase, used to detect performance regressions.
+///
+/// Baselines are stored in a text file where each non-empty line (other than comments starting
+/// with '#') is in the format "<day> <phase> <nanoseconds>", with the phase being one of "input",
+/// "part1" or "part2".
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Baseline {
+    durations: BTreeMap<(u64, Phase), Duration>,
+}
+
+/// Phase of a problem that was slower than the baseline by more than the threshold.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Regression {
+    pub day: u64,
+    pub phase: Phase,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Percentage increase in execution time over the baseline.
+    pub change_percent: f64,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {:02} {}: {:.2?} -> {:.2?} (+{:.1}%)",
+            self.day,
+            self.phase.label(),
+            self.baseline,
+            self.current,
+            self.change_percent
+        )
+    }
+}
+
+impl Baseline {
+    /// Creates a baseline from the execution times of each phase in the reports. Reports for
+    /// problems that timed out or panicked are skipped.
+    pub fn from_reports(reports: &[DayReport]) -> Self {
+        let durations = reports
+            .iter()
+            .filter(|report| report.completed())
+            .flat_map(|report| {
+                report
+                    .phase_durations()
+                    .into_iter()
+                    .map(|(phase, duration)| ((report.day, phase), duration))
+            })
+            .collect::<BTreeMap<(u64, Phase), Duration>>();
+        Self { durations }
+    }
+
+    /// Loads the baseline from the given file. Relative paths are resolved from the crate root.
+    ///
+    /// Returns an error if the file cannot be read or is not in the expected format.
+    pub fn load(filename: &str) -> Result<Self, ParseError> {
+        let raw_input = input::read_input(filename)?;
+        Self::parse(filename, &raw_input)
+    }
+
+    /// Parses the contents of a baseline file.
+    fn parse(filename: &str, raw_input: &str) -> Result<Self, ParseError> {
+        let mut durations: BTreeMap<(u64, Phase), Duration> = BTreeMap::new();
+        for (i, line) in raw_input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            let day = fields.first().and_then(|s| s.parse::<u64>().ok());
+            let phase = fields.get(1).and_then(|s| Phase::from_key(s));
+            let nanos = fields.get(2).and_then(|s| s.parse::<u64>().ok());
+            match (day, phase, nanos, fields.len()) {
+                (Some(day), Some(phase), Some(nanos), 3) => {
+                    durations.insert((day, phase), Duration::from_nanos(nanos));
+                }
+                _ => {
+                    return Err(ParseError::unexpected(
+                        filename,
+                        i + 1,
+                        1,
+                        "timing in format \"<day> <phase> <nanoseconds>\"",
+                    ))
+                }
+            }
+        }
+        Ok(Self { durations })
+    }
+
+    /// Saves the baseline to the given file, keeping the saved times for any days and phases not
+    /// included in this baseline. Relative paths are resolved from the crate root.
+    pub fn save(&self, filename: &str) -> io::Result<()> {
+        let mut merged = if input::resolve_input_path(filename).is_file() {
+            Self::load(filename).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?
+        } else {
+            Self::default()
+        };
+        merged.durations.extend(self.durations.iter());
+        fs::write(input::resolve_input_path(filename), merged.format())
+    }
+
+    /// Formats the baseline for output to file.
+    fn format(&self) -> String {
+        let mut output = String::from("# day phase nanoseconds\n");
+        for ((day, phase), duration) in &self.durations {
+            writeln!(output, "{day:02} {} {}", phase.key(), duration.as_nanos()).unwrap();
+        }
+        output
+    }
+
+    /// Gets the saved execution time for the given day and phase, if there is one.
+    pub fn duration(&self, day: u64, phase: Phase) -> Option<Duration> {
+        self.durations.get(&(day, phase)).copied()
+    }
+
+    /// Compares the execution times in the reports against the baseline, returning the phases that
+    /// were slower than the baseline by more than the threshold percentage. Phases without a
+    /// saved time, and problems that timed out or panicked, are skipped.
+    pub fn compare(&self, reports: &[DayReport], threshold_percent: f64) -> Vec<Regression> {
+        let mut regressions: Vec<Regression> = vec![];
+        for report in reports.iter().filter(|report| report.completed()) {
+            for (phase, current) in report.phase_durations() {
+                let Some(baseline) = self.duration(report.day, phase) else {
+                    continue;
+                };
+                let change_percent = if baseline.is_zero() {
+                    0.0
+                } else {
+                    (current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0
+                };
+                if change_percent > threshold_percent {
+                    regressions.push(Regression {
+                        day: report.day,
+                        phase,
+                        baseline,
+                        current,
+                        change_percent,
+                    });
+                }
+            }
+        }
+        regressions
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Creates a report for Day 06 with the given phase durations in nanoseconds.
+    fn report(input: u64, part1: u64, part2: Option<u64>) -> DayReport {
+        DayReport {
+            input_duration: Duration::from_nanos(input),
+            part1_duration: Some(Duration::from_nanos(part1)),
+            part2_duration: part2.map(Duration::from_nanos),
+            ..DayReport::for_test(6, [Some(288), part2.map(|_| 71503)])
+        }
+    }
+
+    /// Tests that a baseline survives formatting and parsing.
+    #[test]
+    fn test_baseline_format_parse() {
+        let baseline = Baseline::from_reports(&[report(100, 200, Some(300))]);
+        let formatted = baseline.format();
+        assert_eq!(
+            "# day phase nanoseconds\n06 input 100\n06 part1 200\n06 part2 300\n",
+            formatted
+        );
+        assert_eq!(
+            baseline,
+            Baseline::parse("baseline.txt", &formatted).unwrap()
+        );
+    }
+
+    /// Tests that a malformed line in the baseline file is reported as a parse error.
+    #[test]
+    fn test_baseline_parse_malformed() {
+        let err = Baseline::parse("baseline.txt", "06 input 100\n06 part3 200\n").unwrap_err();
+        assert_eq!(
+            "baseline.txt:2:1: expected timing in format \"<day> <phase> <nanoseconds>\"",
+            err.to_string()
+        );
+    }
+
+    /// Tests that only the phases slower than the baseline by more than the threshold are
+    /// reported as regressions.
+    #[test]
+    fn test_baseline_compare() {
+        let baseline = Baseline::from_reports(&[report(100, 200, None)]);
+        let regressions = baseline.compare(&[report(105, 250, Some(1000))], 10.0);
+        assert_eq!(1, regressions.len());
+        assert_eq!(Phase::Part1, regressions[0].phase);
+        assert_eq!(Duration::from_nanos(200), regressions[0].baseline);
+        assert_eq!(Duration::from_nanos(250), regressions[0].current);
+        assert!((regressions[0].change_percent - 25.0).abs() < 1e-9);
+    }
+
+    /// Tests that saving a baseline keeps the saved times for other days.
+    #[test]
+    fn test_baseline_save_merge() {
+        let filename = input::write_temp_input("baseline.txt", "01 input 50\n06 input 100\n");
+        Baseline::from_reports(&[report(120, 200, None)])
+            .save(&filename)
+            .unwrap();
+        let baseline = Baseline::load(&filename).unwrap();
+        assert_eq!(
+            Some(Duration::from_nanos(50)),
+            baseline.duration(1, Phase::Input)
+        );
+        assert_eq!(
+            Some(Duration::from_nanos(120)),
+            baseline.duration(6, Phase::Input)
+        );
+        assert_eq!(
+            Some(Duration::from_nanos(200)),
+            baseline.duration(6, Phase::Part1)
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::hint;
+use std::time::{Duration, Instant};
+
+use crate::cancel::CancellationToken;
+use crate::runner::{DayReport, Parts, Phase};
+use crate::solution::SolveContext;
+use crate::utils::input;
+use crate::{ParseError, Solution};
+
+/// Summary statistics of the durations recorded over the benchmark runs of a single phase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+}
+
+impl BenchStats {
+    /// Calculates the summary statistics for the given durations.
+    ///
+    /// Panics if no durations are given.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no benchmark samples recorded");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        // Middle value, or mean of the two middle values if there is an even number of samples
+        let median = (sorted[(n - 1) / 2] + sorted[n / 2]) / 2;
+        // Nearest-rank 95th percentile
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+        let mean_nanos = sorted.iter().map(|d| d.as_nanos()).sum::<u128>() as f64 / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_nanos() as f64 - mean_nanos).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean_nanos.round() as u64),
+            p95,
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// Benchmark results for a single problem. The report holds the solutions from the final run,
+/// with the median duration of each phase.
+#[derive(Clone, Debug)]
+pub struct BenchReport {
+    pub report: DayReport,
+    pub iterations: usize,
+    pub input: BenchStats,
+    pub part1: Option<BenchStats>,
+    pub part2: Option<BenchStats>,
+}
+
+/// Calculates the number of warm-up runs completed before recording the benchmark runs.
+fn warmup_iterations(iterations: usize) -> usize {
+    iterations.div_ceil(10)
+}
+
+/// Repeatedly runs the phase, returning the result of the final run and the duration of each of
+/// the recorded runs.
+fn bench_phase<T>(iterations: usize, mut phase: impl FnMut() -> T) -> (T, Vec<Duration>) {
+    for _ in 0..warmup_iterations(iterations) {
+        hint::black_box(phase());
+    }
+    let mut samples: Vec<Duration> = Vec::with_capacity(iterations);
+    let mut output = None;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let result = hint::black_box(phase());
+        samples.push(start.elapsed());
+        output = Some(result);
+    }
+    (output.unwrap(), samples)
+}
+
+/// Benchmarks the input processing and the selected parts of the problem over the given number of
+/// runs for each phase, after warming up.
+///
+/// Returns an error if the input file cannot be read or is not in the expected format.
+pub fn bench<S: Solution>(
+    filename: &str,
+    parts: Parts,
+    context: &SolveContext,
+    iterations: usize,
+) -> Result<BenchReport, ParseError> {
+    // Check that the input file can be processed before benchmarking
+    S::parse_with(filename, context)?;
+    // Input processing - the input file is read on each run, as in a single run
+    let (input, input_samples) = bench_phase(iterations, || S::parse_with(filename, context));
+    let input = input?;
+    let input_stats = BenchStats::from_samples(&input_samples);
+    // Benchmark runs are never cancelled
+    let token = CancellationToken::new();
+    // Solve part 1
+    let (part1, part1_stats) = if parts.includes_part1() {
+        let (p1_solution, samples) = bench_phase(iterations, || S::part1(&input, &token));
+        (Some(p1_solution), Some(BenchStats::from_samples(&samples)))
+    } else {
+        (None, None)
+    };
+    // Solve part 2
+    let (part2, part2_stats) = if parts.includes_part2() {
+        let (p2_solution, samples) = bench_phase(iterations, || S::part2(&input, &token));
+        (Some(p2_solution), Some(BenchStats::from_samples(&samples)))
+    } else {
+        (None, None)
+    };
+    Ok(BenchReport {
+        report: DayReport {
+            day: S::PROBLEM_DAY,
+            name: S::PROBLEM_NAME,
+            part1,
+            part2,
+            input_duration: input_stats.median,
+            part1_duration: part1_stats.map(|stats| stats.median),
+            part2_duration: part2_stats.map(|stats| stats.median),
+            timeout: None,
+            panic: None,
+            error: None,
+            alloc_stats: vec![],
+        },
+        iterations,
+        input: input_stats,
+        part1: part1_stats,
+        part2: part2_stats,
+    })
+}
+
+/// Prints the solutions and benchmark statistics for a single problem to stdout.
+pub fn print_bench_report(bench_report: &BenchReport) {
+    let report = &bench_report.report;
+    println!("==================================================");
+    println!("AOC 2023 Day {} - \"{}\"", report.day, report.name);
+    if let Some(p1_solution) = &report.part1 {
+        println!("[+] Part 1: {p1_solution}");
+    }
+    if let Some(p2_solution) = &report.part2 {
+        println!("[+] Part 2: {p2_solution}");
+    }
+    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
+    println!(
+        "Benchmark ({} runs, {} warm-up):",
+        bench_report.iterations,
+        warmup_iterations(bench_report.iterations)
+    );
+    println!(
+        "            {:>10} {:>10} {:>10} {:>10} {:>10}",
+        "min", "median", "mean", "p95", "std dev"
+    );
+    for (phase, stats) in phase_stats(bench_report) {
+        println!(
+            "[+] {:<7} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            format!("{}:", phase.label()),
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.mean),
+            format!("{:.2?}", stats.p95),
+            format!("{:.2?}", stats.std_dev),
+        );
+    }
+    println!("==================================================");
+}
+
+/// Gets the benchmark statistics for each phase included in the benchmark.
+fn phase_stats(bench_report: &BenchReport) -> Vec<(Phase, BenchStats)> {
+    let mut phases = vec![(Phase::Input, bench_report.input)];
+    if let Some(stats) = bench_report.part1 {
+        phases.push((Phase::Part1, stats));
+    }
+    if let Some(stats) = bench_report.part2 {
+        phases.push((Phase::Part2, stats));
+    }
+    phases
+}
+
+/// Formats the benchmark results for output to file. Each line gives the statistics for one phase
+/// of a problem in nanoseconds, so the results can be compared between runs using diff.
+pub fn format_bench_output(bench_reports: &[BenchReport]) -> String {
+    let mut output = String::from("# day phase runs min_ns median_ns mean_ns p95_ns std_dev_ns\n");
+    for bench_report in bench_reports {
+        for (phase, stats) in phase_stats(bench_report) {
+            writeln!(
+                output,
+                "{:02} {} {} {} {} {} {} {}",
+                bench_report.report.day,
+                phase.key(),
+                bench_report.iterations,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.p95.as_nanos(),
+                stats.std_dev.as_nanos(),
+            )
+            .unwrap();
+        }
+    }
+    output
+}
+
+/// Writes the benchmark results to the given file. Relative paths are resolved from the crate
+/// root.
+pub fn write_bench_output(filename: &str, bench_reports: &[BenchReport]) -> std::io::Result<()> {
+    fs::write(
+        input::resolve_input_path(filename),
+        format_bench_output(bench_reports),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the summary statistics calculated from a set of samples.
+    #[test]
+    fn test_bench_stats_from_samples() {
+        let samples = [4, 1, 3, 2, 10, 2, 3, 3]
+            .iter()
+            .map(|&n| Duration::from_nanos(n))
+            .collect::<Vec<Duration>>();
+        let stats = BenchStats::from_samples(&samples);
+        assert_eq!(Duration::from_nanos(1), stats.min);
+        assert_eq!(Duration::from_nanos(3), stats.median);
+        assert_eq!(Duration::from_nanos(4), stats.mean);
+        assert_eq!(Duration::from_nanos(10), stats.p95);
+        assert_eq!(Duration::from_nanos(3), stats.std_dev);
+    }
+
+    /// Tests the file output format for benchmark results.
+    #[test]
+    fn test_format_bench_output() {
+        let stats = BenchStats::from_samples(&[Duration::from_nanos(5)]);
+        let bench_report = BenchReport {
+            report: DayReport {
+                input_duration: stats.median,
+                part2_duration: Some(stats.median),
+                ..DayReport::for_test(6, [None, Some(71503)])
+            },
+            iterations: 1,
+            input: stats,
+            part1: None,
+            part2: Some(stats),
+        };
+        assert_eq!(
+            "# day phase runs min_ns median_ns mean_ns p95_ns std_dev_ns\n\
+             06 input 1 5 5 5 5 0\n\
+             06 part2 1 5 5 5 5 0\n",
+            format_bench_output(&[bench_report])
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt::Write as _;
+
+use crate::days::day01::{self, CalibrationExplanation, CalibrationToken};
+use crate::output;
+use crate::utils::scanner::TokenScanner;
+
+/// Report explaining the calibration value found on every line of a Day 01 calibration document,
+/// used to find which tokens were picked when an answer is wrong.
+#[derive(Clone, Debug)]
+pub struct CalibrationReport {
+    pub part: u8,
+    /// Each non-empty line of the document with its line number in the input file, starting at
+    /// 1, and the explanation of its calibration value. Token offsets are byte offsets into the
+    /// line as it appears in the input file.
+    pub lines: Vec<(usize, String, CalibrationExplanation)>,
+}
+
+impl CalibrationReport {
+    /// Creates a new [`CalibrationReport`] for the raw contents of the calibration document, using
+    /// the scanner for the given part. Empty lines are skipped, as they are by the solver.
+    pub fn new(part: u8, raw_input: &str, scanner: &TokenScanner<String>) -> Self {
+        let lines = raw_input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                // The solver trims each line, so shift the tokens back to their offsets in the line
+                let trim_offset = line.len() - line.trim_start().len();
+                let mut explanation = day01::explain_calibration_value(line.trim(), scanner);
+                if let Some((first, last)) = explanation.tokens.as_mut() {
+                    for token in [first, last] {
+                        token.start += trim_offset;
+                        token.end += trim_offset;
+                    }
+                }
+                (i + 1, line.to_string(), explanation)
+            })
+            .collect();
+        Self { part, lines }
+    }
+
+    /// Calculates the sum of the calibration values, the same as the solver for the part.
+    pub fn sum(&self) -> u64 {
+        self.lines
+            .iter()
+            .filter_map(|(_, _, explanation)| explanation.value)
+            .sum()
+    }
+
+    /// Counts the lines without a calibration value, which are skipped by the solver.
+    pub fn skipped(&self) -> usize {
+        self.lines
+            .iter()
+            .filter(|(_, _, explanation)| explanation.value.is_none())
+            .count()
+    }
+
+    /// Formats the report with a line for each line of the document, followed by a summary.
+    /// Tokens are given with their byte range in the line, and skipped lines are flagged.
+    pub fn format_text(&self) -> String {
+        let mut output = String::new();
+        for (line_num, line, explanation) in &self.lines {
+            write!(output, "Line {line_num}: {line:?} - ").unwrap();
+            match (&explanation.tokens, explanation.value) {
+                (Some((first, last)), Some(value)) => writeln!(
+                    output,
+                    "first {}, last {} = {value}",
+                    format_token(first),
+                    format_token(last)
+                ),
+                _ => writeln!(output, "SKIPPED (no calibration value)"),
+            }
+            .unwrap();
+        }
+        writeln!(
+            output,
+            "[*] Day 01 Part {}: {} lines, {} skipped, sum {}",
+            self.part,
+            self.lines.len(),
+            self.skipped(),
+            self.sum()
+        )
+        .unwrap();
+        output
+    }
+
+    /// Formats the report as a JSON object, with a record for each line of the document. The
+    /// tokens and value of skipped lines are null.
+    pub fn format_json(&self) -> String {
+        let lines = self
+            .lines
+            .iter()
+            .map(|(line_num, line, explanation)| {
+                let (first, last) = match &explanation.tokens {
+                    Some((first, last)) => (token_json(first), token_json(last)),
+                    None => (String::from("null"), String::from("null")),
+                };
+                let value = explanation
+                    .value
+                    .map_or(String::from("null"), |value| value.to_string());
+                format!(
+                    "    {{\"line\": {}, \"text\": {}, \"first\": {first}, \"last\": {last}, \
+                     \"value\": {value}, \"skipped\": {}}}",
+                    line_num,
+                    output::escape_json(line),
+                    explanation.value.is_none()
+                )
+            })
+            .collect::<Vec<String>>();
+        let lines = if lines.is_empty() {
+            String::from("[]")
+        } else {
+            format!("[\n{}\n  ]", lines.join(",\n"))
+        };
+        format!(
+            "{{\n  \"part\": {},\n  \"sum\": {},\n  \"skipped\": {},\n  \"lines\": {lines}\n}}\n",
+            self.part,
+            self.sum(),
+            self.skipped()
+        )
+    }
+}
+
+/// Formats the token with its byte range for the text report, e.g. "two" [4..7].
+fn format_token(token: &CalibrationToken) -> String {
+    format!("{:?} [{}..{}]", token.token, token.start, token.end)
+}
+
+/// Formats the token as a JSON object.
+fn token_json(token: &CalibrationToken) -> String {
+    format!(
+        "{{\"token\": {}, \"start\": {}, \"end\": {}, \"digits\": {}}}",
+        output::escape_json(&token.token),
+        token.start,
+        token.end,
+        output::escape_json(&token.digits)
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::vocab::Vocabulary;
+
+    /// Creates a Part 2 report for a document with one skipped line.
+    fn report() -> CalibrationReport {
+        CalibrationReport::new(2, "eightwo\nabc\n", &Vocabulary::english().scanner())
+    }
+
+    /// Tests that lines are numbered and tokens are located as in the input file, where the
+    /// document has empty lines and indented lines.
+    #[test]
+    fn test_line_numbers_and_offsets() {
+        let report = CalibrationReport::new(
+            2,
+            "\n  xtwone3\n\n\tabc\n",
+            &Vocabulary::english().scanner(),
+        );
+        assert_eq!(
+            "Line 2: \"  xtwone3\" - first \"two\" [3..6], last \"3\" [8..9] = 23\n\
+             Line 4: \"\\tabc\" - SKIPPED (no calibration value)\n\
+             [*] Day 01 Part 2: 2 lines, 1 skipped, sum 23\n",
+            report.format_text()
+        );
+    }
+
+    /// Tests the text report, flagging the skipped line.
+    #[test]
+    fn test_format_text() {
+        assert_eq!(
+            "Line 1: \"eightwo\" - first \"eight\" [0..5], last \"two\" [4..7] = 82\n\
+             Line 2: \"abc\" - SKIPPED (no calibration value)\n\
+             [*] Day 01 Part 2: 2 lines, 1 skipped, sum 82\n",
+            report().format_text()
+        );
+    }
+
+    /// Tests the JSON report, with null tokens and value for the skipped line.
+    #[test]
+    fn test_format_json() {
+        assert_eq!(
+            "{\n  \"part\": 2,\n  \"sum\": 82,\n  \"skipped\": 1,\n  \"lines\": [\n    \
+             {\"line\": 1, \"text\": \"eightwo\", \
+             \"first\": {\"token\": \"eight\", \"start\": 0, \"end\": 5, \"digits\": \"8\"}, \
+             \"last\": {\"token\": \"two\", \"start\": 4, \"end\": 7, \"digits\": \"2\"}, \
+             \"value\": 82, \"skipped\": false},\n    \
+             {\"line\": 2, \"text\": \"abc\", \"first\": null, \"last\": null, \
+             \"value\": null, \"skipped\": true}\n  ]\n}\n",
+            report().format_json()
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::Answer;
+
+/// Token used to cooperatively cancel a solver that has run past its time budget.
+///
+/// Solvers with long-running loops should check [`CancellationToken::is_cancelled`] regularly and
+/// return early once the token is cancelled. Any answer returned after cancellation is discarded,
+/// so an early return can use any placeholder answer. Solvers that do not check the token run to
+/// completion, but are still reported as having timed out.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl CancellationToken {
+    /// Creates a new [`CancellationToken`] that is only cancelled by calling
+    /// [`CancellationToken::cancel`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`CancellationToken`] that is cancelled once the given time has elapsed.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            deadline: Some(Instant::now() + timeout),
+        }
+    }
+
+    /// Cancels the token, and all clones of it.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Checks if the token has been cancelled, or its deadline has passed.
+    pub fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return true;
+        }
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            self.cancel();
+            return true;
+        }
+        false
+    }
+}
+
+/// Gets the placeholder answer returned by a solver that stops early once its cancellation token
+/// is cancelled. The answer is discarded, as the part is reported as timed out.
+pub fn cancelled_answer() -> Answer {
+    Answer::Text(String::from("cancelled"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that cancelling a token also cancels its clones.
+    #[test]
+    fn test_cancellation_token_cancel() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        token.cancel();
+        assert!(clone.is_cancelled());
+    }
+
+    /// Tests that a token is cancelled once its timeout has elapsed.
+    #[test]
+    fn test_cancellation_token_timeout() {
+        assert!(CancellationToken::with_timeout(Duration::ZERO).is_cancelled());
+        assert!(!CancellationToken::with_timeout(Duration::from_secs(60)).is_cancelled());
+    }
+}
@@ -0,0 +1,204 @@
+use std::fmt;
+
+use crate::answers::{AnswerRegistry, Verdict};
+use crate::cancel::CancellationToken;
+use crate::examples::{self, ExampleOutcome, ExampleResult, EXAMPLES_DIR};
+use crate::runner::{DaySolver, Parts};
+use crate::solution::SolveContext;
+use crate::{Answer, ParseError};
+
+/// Result of checking the solver for one part of a problem against the answer recorded for the
+/// problem input file in the answer registry. The part is None for outcomes that apply to the
+/// input file as a whole.
+#[derive(Clone, Debug)]
+pub struct InputResult {
+    pub day: u64,
+    pub part: Option<u8>,
+    pub outcome: ExampleOutcome,
+}
+
+impl InputResult {
+    /// Checks if the solver produced the expected answer.
+    pub fn is_pass(&self) -> bool {
+        self.outcome == ExampleOutcome::Pass
+    }
+}
+
+impl fmt::Display for InputResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {:02} input", self.day)?;
+        if let Some(part) = self.part {
+            write!(f, " part {part}")?;
+        }
+        match &self.outcome {
+            ExampleOutcome::Pass => write!(f, ": PASS"),
+            ExampleOutcome::Fail { expected, actual } => {
+                write!(f, ": FAIL (expected {expected}, got {actual})")
+            }
+            ExampleOutcome::Error(message) => write!(f, ": ERROR ({message})"),
+            ExampleOutcome::MissingExpected => write!(f, ": MISSING (no answer recorded)"),
+            ExampleOutcome::Unrecorded => write!(f, ": SKIPPED (no answer recorded)"),
+        }
+    }
+}
+
+/// Verdict for the answer to one part of a problem solved using the problem input file.
+#[derive(Clone, Debug)]
+pub struct Verification {
+    pub day: u64,
+    pub part: u8,
+    pub answer: Answer,
+    pub verdict: Verdict,
+}
+
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {:02} Part {}: {}",
+            self.day, self.part, self.verdict
+        )
+    }
+}
+
+/// Solves the problem using the problem input file, and verifies the answer to each part against
+/// the answer registry without revealing the expected answers.
+///
+/// Returns an error if the input file cannot be read or is not in the expected format.
+pub fn verify_input(
+    solver: &DaySolver,
+    registry: &AnswerRegistry,
+) -> Result<Vec<Verification>, ParseError> {
+    let filename = solver.select_input_file(None);
+    let report = solver.solve(
+        Some(&filename),
+        Parts::Both,
+        &SolveContext::default(),
+        &CancellationToken::new(),
+    )?;
+    Ok([(1, report.part1), (2, report.part2)]
+        .into_iter()
+        .filter_map(|(part, answer)| answer.map(|answer| (part, answer)))
+        .map(|(part, answer)| Verification {
+            day: solver.day(),
+            part,
+            verdict: registry.verify(solver.day(), part, &filename, &answer),
+            answer,
+        })
+        .collect())
+}
+
+/// Checks the solver against the problem input file, for each part with an answer recorded in the
+/// answer registry.
+pub fn check_input(solver: &DaySolver, registry: &AnswerRegistry) -> Vec<InputResult> {
+    let result = |part: Option<u8>, outcome: ExampleOutcome| InputResult {
+        day: solver.day(),
+        part,
+        outcome,
+    };
+    let filename = solver.select_input_file(None);
+    if [1, 2]
+        .iter()
+        .all(|&part| registry.expected(solver.day(), part, &filename).is_none())
+    {
+        return vec![result(None, ExampleOutcome::MissingExpected)];
+    }
+    let verifications = match verify_input(solver, registry) {
+        Ok(verifications) => verifications,
+        Err(err) => return vec![result(None, ExampleOutcome::Error(err.to_string()))],
+    };
+    verifications
+        .into_iter()
+        .filter_map(|verification| {
+            let outcome = match verification.verdict {
+                Verdict::Correct => ExampleOutcome::Pass,
+                Verdict::Wrong => ExampleOutcome::Fail {
+                    expected: registry
+                        .expected(solver.day(), verification.part, &filename)
+                        .map(|expected| expected.to_string())
+                        .unwrap_or_default(),
+                    actual: verification.answer.to_string(),
+                },
+                Verdict::Unknown => return None,
+            };
+            Some(result(Some(verification.part), outcome))
+        })
+        .collect()
+}
+
+/// Checks the solver for the given day against its example inputs and the problem input file,
+/// printing a compact pass/fail line for each check to stdout.
+///
+/// Returns an error message if the day has not been implemented, or if the example inputs or
+/// answer registry cannot be loaded. Otherwise, returns whether all of the checks passed.
+pub fn check_day(day: u64, registry: &[DaySolver]) -> Result<bool, String> {
+    let Some(solver) = registry.iter().find(|solver| solver.day() == day) else {
+        return Err(format!("Day {day} has not been implemented"));
+    };
+    let examples = examples::discover_examples(EXAMPLES_DIR).map_err(|err| err.to_string())?;
+    let example_results = examples
+        .iter()
+        .filter(|example| example.day == day)
+        .flat_map(|example| examples::check_example(example, registry))
+        .collect::<Vec<ExampleResult>>();
+    let answer_registry = AnswerRegistry::load().map_err(|err| err.to_string())?;
+    let input_results = check_input(solver, &answer_registry);
+    let mut passed = 0;
+    let mut total = 0;
+    for (line, outcome) in example_results
+        .iter()
+        .map(|result| (result.to_string(), &result.outcome))
+        .chain(
+            input_results
+                .iter()
+                .map(|result| (result.to_string(), &result.outcome)),
+        )
+    {
+        // Examples without recorded answers (e.g. for a new day) are not counted as checks
+        if *outcome == ExampleOutcome::Unrecorded {
+            println!("[*] {line}");
+            continue;
+        }
+        let is_pass = *outcome == ExampleOutcome::Pass;
+        println!("[{}] {line}", if is_pass { "+" } else { "!" });
+        total += 1;
+        passed += usize::from(is_pass);
+    }
+    println!(
+        "[*] Day {day:02} - \"{}\": {passed}/{total} checks passed",
+        solver.name()
+    );
+    Ok(passed == total)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::answers;
+    use crate::days;
+    use crate::utils::input;
+
+    /// Tests that the problem input file is checked against the answers in the registry.
+    #[test]
+    fn test_check_input() {
+        let solver = days::registry()[5];
+        if !input::test_input_available(&solver.select_input_file(None)) {
+            return;
+        }
+        let results = check_input(&solver, &AnswerRegistry::load().unwrap());
+        assert_eq!(2, results.len());
+        assert!(results.iter().all(|result| result.is_pass()));
+        // Wrong answers are reported without revealing hashed expected answers
+        let filename = solver.select_input_file(None);
+        let (raw_input, _) =
+            answers::hash_answers("answers.txt", &format!("6 2 {filename} 1\n")).unwrap();
+        let results = check_input(
+            &solver,
+            &AnswerRegistry::parse("answers.txt", &raw_input).unwrap(),
+        );
+        assert_eq!(1, results.len());
+        assert!(results[0]
+            .to_string()
+            .starts_with("Day 06 input part 2: FAIL (expected hashed answer, got "));
+    }
+}
@@ -0,0 +1,243 @@
+use std::time::Duration;
+
+use crate::baseline::DEFAULT_THRESHOLD_PERCENT;
+use crate::output::OutputFormat;
+use crate::runner::Parts;
+use crate::solution::SolveContext;
+use crate::vocab::Vocabulary;
+
+/// Usage text for the options accepted by [`parse_run_option`].
+pub const RUN_OPTIONS_USAGE: &str = "  -p, --part <1|2>           Only solve the given part
+  -i, --input <PATH>         Read the input from the given file (\"-\" for stdin), single day only
+  -f, --format <FORMAT>      Print the results as text (default), json or csv
+  -j, --jobs <N>             Solve up to N days at the same time [default: 1]
+  --timeout <SECONDS>        Stop solving a day once it has run for the given number of seconds
+  -v, --explain              Print the debug events emitted while solving to stderr
+  --vocab <NAME|PATH>        Number words read by Day 01 Part 2: english (default),
+                             english-extended, or a vocabulary file of \"<token> <digits>\" lines
+  --bench <N>                Benchmark each phase over N runs, after warming up
+  --bench-output <PATH>      Write the benchmark results to the given file, e.g. bench_output.txt
+  --save-baseline <PATH>     Save the execution time of each phase to the given baseline file
+  --baseline <PATH>          Fail if any phase is slower than in the given baseline file
+  --threshold <PERCENT>      Slowdown allowed before a phase counts as a regression [default: 10]
+
+Input files are read from the AOC_INPUT_DIR directory if set, otherwise from \"./input\". Relative
+paths are resolved from the crate root.";
+
+/// Options controlling how problems are solved and reported, shared by the runner and the
+/// binaries for each day.
+#[derive(Clone, Debug)]
+pub struct RunOptions {
+    pub parts: Parts,
+    pub input_file: Option<String>,
+    pub format: OutputFormat,
+    /// Number of worker threads used to solve problems at the same time.
+    pub jobs: usize,
+    /// Time budget for solving each problem. Problems are not time limited if None.
+    pub timeout: Option<Duration>,
+    /// Prints the tracing spans and debug events emitted by the solvers if true.
+    pub explain: bool,
+    /// Vocabulary used to read number words in Day 01, selected with `--vocab` or by
+    /// [`RunOptions::resolve_vocab`]. The default vocabulary is used if None.
+    pub vocab: Option<Vocabulary>,
+    /// Number of benchmark runs for each phase. Problems are solved once if None.
+    pub bench_iterations: Option<usize>,
+    pub bench_output: Option<String>,
+    pub save_baseline: Option<String>,
+    pub baseline: Option<String>,
+    /// Percentage by which a phase must be slower than the baseline to count as a regression.
+    pub threshold_percent: f64,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            parts: Parts::Both,
+            input_file: None,
+            format: OutputFormat::Text,
+            jobs: 1,
+            timeout: None,
+            explain: false,
+            vocab: None,
+            bench_iterations: None,
+            bench_output: None,
+            save_baseline: None,
+            baseline: None,
+            threshold_percent: DEFAULT_THRESHOLD_PERCENT,
+        }
+    }
+}
+
+impl RunOptions {
+    /// Checks that the combination of options is valid, returning an error message if not.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.bench_output.is_some() && self.bench_iterations.is_none() {
+            return Err(String::from("--bench-output can only be used with --bench"));
+        }
+        if self.timeout.is_some() && self.bench_iterations.is_some() {
+            return Err(String::from("--timeout cannot be used with --bench"));
+        }
+        if self.explain && self.bench_iterations.is_some() {
+            return Err(String::from("--explain cannot be used with --bench"));
+        }
+        Ok(())
+    }
+
+    /// Selects the vocabulary named by the `AOC_VOCAB` environment variable if no vocabulary was
+    /// given with `--vocab`. Called once the command line has been parsed, so the environment
+    /// variable is only read in one place.
+    pub fn resolve_vocab(&mut self) -> Result<(), String> {
+        if self.vocab.is_none() {
+            self.vocab =
+                Some(Vocabulary::from_env().map_err(|err| format!("invalid vocabulary: {err}"))?);
+        }
+        Ok(())
+    }
+
+    /// Creates the solve context passed to the solvers for the options.
+    pub fn solve_context(&self) -> SolveContext {
+        SolveContext::new(self.vocab.clone().unwrap_or_default())
+    }
+}
+
+/// Parses the run option given by the argument, taking the option value (if required) from the
+/// remaining arguments.
+///
+/// Returns Ok(false) if the argument is not a run option, or an error message if the option value
+/// is missing or invalid.
+pub fn parse_run_option<'a>(
+    arg: &str,
+    args: &mut impl Iterator<Item = &'a String>,
+    options: &mut RunOptions,
+) -> Result<bool, String> {
+    let mut value = || {
+        args.next()
+            .map(|s| s.as_str())
+            .ok_or(format!("missing value for {arg}"))
+    };
+    match arg {
+        "--part" | "-p" => {
+            options.parts = match value()? {
+                "1" => Parts::Part1,
+                "2" => Parts::Part2,
+                other => return Err(format!("invalid part: {other}")),
+            }
+        }
+        "--input" | "-i" => options.input_file = Some(value()?.to_string()),
+        "--format" | "-f" => {
+            let name = value()?;
+            options.format =
+                OutputFormat::from_name(name).ok_or(format!("invalid output format: {name}"))?;
+        }
+        "--jobs" | "-j" => {
+            let jobs = value()?;
+            match jobs.parse::<usize>() {
+                Ok(n) if n > 0 => options.jobs = n,
+                _ => return Err(format!("invalid number of jobs: {jobs}")),
+            }
+        }
+        "--timeout" => {
+            let timeout = value()?;
+            match timeout.parse::<f64>().map(Duration::try_from_secs_f64) {
+                Ok(Ok(duration)) if !duration.is_zero() => options.timeout = Some(duration),
+                _ => return Err(format!("invalid timeout: {timeout}")),
+            }
+        }
+        "--explain" | "-v" => options.explain = true,
+        "--vocab" => {
+            let name = value()?;
+            let vocabulary =
+                Vocabulary::select(name).map_err(|err| format!("invalid vocabulary: {err}"))?;
+            options.vocab = Some(vocabulary);
+        }
+        "--bench" => {
+            let iterations = value()?;
+            match iterations.parse::<usize>() {
+                Ok(n) if n > 0 => options.bench_iterations = Some(n),
+                _ => return Err(format!("invalid number of benchmark runs: {iterations}")),
+            }
+        }
+        "--bench-output" => options.bench_output = Some(value()?.to_string()),
+        "--save-baseline" => options.save_baseline = Some(value()?.to_string()),
+        "--baseline" => options.baseline = Some(value()?.to_string()),
+        "--threshold" => {
+            let threshold = value()?;
+            match threshold.parse::<f64>() {
+                Ok(percent) if percent.is_finite() && percent >= 0.0 => {
+                    options.threshold_percent = percent
+                }
+                _ => return Err(format!("invalid regression threshold: {threshold}")),
+            }
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Parses the given arguments as run options.
+    fn parse(args: &[&str]) -> Result<RunOptions, String> {
+        let args = args.iter().map(|s| s.to_string()).collect::<Vec<String>>();
+        let mut args = args.iter();
+        let mut options = RunOptions::default();
+        while let Some(arg) = args.next() {
+            if !parse_run_option(arg, &mut args, &mut options)? {
+                return Err(format!("unexpected argument: {arg}"));
+            }
+        }
+        Ok(options)
+    }
+
+    /// Tests that the run options are parsed with their values.
+    #[test]
+    fn test_parse_run_option() {
+        let options = parse(&["-p", "2", "--bench", "10", "--input", "-", "-j", "4"]).unwrap();
+        assert_eq!(Parts::Part2, options.parts);
+        assert_eq!(Some(10), options.bench_iterations);
+        assert_eq!(Some(String::from("-")), options.input_file);
+        assert_eq!(4, options.jobs);
+        let options = parse(&["--timeout", "2.5", "--vocab", "english-extended"]).unwrap();
+        assert_eq!(Some(Duration::from_millis(2500)), options.timeout);
+        assert_eq!(Some(Vocabulary::english_extended()), options.vocab);
+        let options = parse(&[
+            "--baseline",
+            "baseline.txt",
+            "--threshold",
+            "2.5",
+            "-f",
+            "csv",
+        ])
+        .unwrap();
+        assert_eq!(Some(String::from("baseline.txt")), options.baseline);
+        assert_eq!(2.5, options.threshold_percent);
+        assert_eq!(OutputFormat::Csv, options.format);
+    }
+
+    /// Tests that invalid and missing option values are reported.
+    #[test]
+    fn test_parse_run_option_invalid() {
+        assert_eq!(
+            Err(String::from("invalid number of benchmark runs: 0")),
+            parse(&["--bench", "0"]).map(|_| ())
+        );
+        assert_eq!(
+            Err(String::from("missing value for --part")),
+            parse(&["--part"]).map(|_| ())
+        );
+        assert_eq!(
+            Err(String::from("invalid regression threshold: -5")),
+            parse(&["--threshold", "-5"]).map(|_| ())
+        );
+        assert_eq!(
+            Err(String::from("invalid output format: xml")),
+            parse(&["--format", "xml"]).map(|_| ())
+        );
+        assert_eq!(
+            Err(String::from("invalid timeout: 0")),
+            parse(&["--timeout", "0"]).map(|_| ())
+        );
+    }
+}
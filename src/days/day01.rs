@@ -0,0 +1,233 @@
+use lazy_static::lazy_static;
+use tracing::debug;
+
+use crate::cancel::CancellationToken;
+use crate::solution::SolveContext;
+use crate::utils::input;
+use crate::utils::scanner::{TokenMatch, TokenScanner};
+use crate::vocab::Vocabulary;
+use crate::{Answer, ParseError, Solution};
+
+const PROBLEM_NAME: &str = "Trebuchet?!";
+const PROBLEM_INPUT_FILE: &str = "./input/day01.txt";
+const PROBLEM_DAY: u64 = 1;
+
+lazy_static! {
+    /// Finds digit characters
+    pub static ref DIGIT_SCANNER: TokenScanner<String> = Vocabulary::digits().scanner();
+}
+
+/// Calibration document lines, with the vocabulary used to read the number words in them.
+#[derive(Clone, Debug)]
+pub struct CalibrationDocument {
+    pub lines: Vec<String>,
+    pub vocabulary: Vocabulary,
+}
+
+/// Token found in a calibration document line, with its byte offsets into the line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CalibrationToken {
+    pub token: String,
+    /// Byte offset of the start of the token.
+    pub start: usize,
+    /// Byte offset one past the end of the token.
+    pub end: usize,
+    /// Digits contributed by the token.
+    pub digits: String,
+}
+
+/// First and last tokens found in a calibration document line, and the calibration value they
+/// combine to. Lines without any tokens have no calibration value, and are skipped by the solvers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CalibrationExplanation {
+    pub tokens: Option<(CalibrationToken, CalibrationToken)>,
+    pub value: Option<u64>,
+}
+
+/// Solver for AOC 2023 Day 01.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const PROBLEM_NAME: &'static str = PROBLEM_NAME;
+    const PROBLEM_INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PROBLEM_DAY: u64 = PROBLEM_DAY;
+
+    type Input = CalibrationDocument;
+
+    /// Processes the input file, using the default vocabulary.
+    fn parse(filename: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(filename, &SolveContext::default())
+    }
+
+    /// Processes the input file, using the vocabulary selected in the solve context.
+    fn parse_with(filename: &str, context: &SolveContext) -> Result<Self::Input, ParseError> {
+        Ok(CalibrationDocument {
+            lines: process_input_file(filename)?,
+            vocabulary: context.vocabulary.clone(),
+        })
+    }
+
+    fn part1(input: &Self::Input, _token: &CancellationToken) -> Answer {
+        solve_part1(&input.lines)
+    }
+
+    fn part2(input: &Self::Input, _token: &CancellationToken) -> Answer {
+        solve_part2(&input.lines, &input.vocabulary)
+    }
+}
+
+/// Processes the AOC 2023 Day 01 input file in the format required by the solver functions.
+///
+/// Returned value is vector of strings given by the lines of the input file.
+pub fn process_input_file(filename: &str) -> Result<Vec<String>, ParseError> {
+    // Read contents of problem input file
+    let raw_input = input::read_input(filename)?;
+    // Process input file contents into data structure
+    Ok(raw_input
+        .lines()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<String>>())
+}
+
+/// Solves AOC 2023 Day 01 Part 1.
+///
+/// Determines the sum of the calibration values extracted from the input strings. The calibration
+/// values are found by extracting and combining the two digits located in each respective input
+/// string.
+pub fn solve_part1(input: &[String]) -> Answer {
+    input
+        .iter()
+        .filter_map(|s| extract_calibration_value(s, &DIGIT_SCANNER))
+        .sum::<u64>()
+        .into()
+}
+
+/// Solves AOC 2023 Day 01 Part 2.
+///
+/// Determines the sum of the calibration values extracted from the input strings. The calibration
+/// values are found by extracting and combining the first and last digits encoded in each
+/// respective input string as a digit character or number word from the vocabulary.
+pub fn solve_part2(input: &[String], vocabulary: &Vocabulary) -> Answer {
+    let scanner = vocabulary.scanner();
+    input
+        .iter()
+        .filter_map(|s| extract_calibration_value(s, &scanner))
+        .sum::<u64>()
+        .into()
+}
+
+/// Extracts the calibration value from the given string, combining the first digit of the first
+/// token and the last digit of the last token found by the scanner. Overlapping tokens are all
+/// considered, so the last token in "eightwo" is "two".
+///
+/// Returns None if the string is in the incorrect format and does not contain a calibration value.
+pub fn extract_calibration_value(s: &str, scanner: &TokenScanner<String>) -> Option<u64> {
+    let Some((first, last)) = scanner.first_and_last(s) else {
+        debug!(line = s, "no calibration value");
+        return None;
+    };
+    let value = combine_digits(first.value, last.value)?;
+    debug!(line = s, value, "calibration value");
+    Some(value)
+}
+
+/// Explains how the calibration value is extracted from the given string, giving the first and
+/// last tokens found by the scanner (see [`extract_calibration_value`]).
+pub fn explain_calibration_value(
+    s: &str,
+    scanner: &TokenScanner<String>,
+) -> CalibrationExplanation {
+    let Some((first, last)) = scanner.first_and_last(s) else {
+        return CalibrationExplanation {
+            tokens: None,
+            value: None,
+        };
+    };
+    let value = combine_digits(first.value, last.value);
+    let to_token = |found: TokenMatch<String>| CalibrationToken {
+        token: found.token(s).to_string(),
+        start: found.start,
+        end: found.end,
+        digits: found.value.to_string(),
+    };
+    CalibrationExplanation {
+        tokens: Some((to_token(first), to_token(last))),
+        value,
+    }
+}
+
+/// Combines the first digit of the first token with the last digit of the last token into a
+/// calibration value.
+fn combine_digits(first: &str, last: &str) -> Option<u64> {
+    let first_digit = first.chars().next()?;
+    let last_digit = last.chars().next_back()?;
+    format!("{first_digit}{last_digit}").parse::<u64>().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::answers;
+    use crate::runner::{self, Parts};
+
+    /// Tests the Day 01 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day01_part1_actual() {
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part1(&input);
+        answers::assert_answer(PROBLEM_DAY, 1, &filename, solution);
+    }
+
+    /// Tests the Day 01 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day01_part2_actual() {
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part2(&input, &Vocabulary::english());
+        answers::assert_answer(PROBLEM_DAY, 2, &filename, solution);
+    }
+
+    /// Tests that multi-digit number words contribute all of their digits to the calibration value.
+    #[test]
+    fn test_day01_part2_extended_vocabulary() {
+        let input = ["twelve", "zero3", "7fourteen", "nineteenine"].map(String::from);
+        let solution = solve_part2(&input, &Vocabulary::english_extended());
+        assert_eq!(Answer::Unsigned(12 + 3 + 74 + 19), solution);
+    }
+
+    /// Tests that the first and last tokens are given with their byte offsets into the line.
+    #[test]
+    fn test_explain_calibration_value() {
+        let scanner = Vocabulary::english().scanner();
+        let explanation = explain_calibration_value("7pqrstsixteen", &scanner);
+        let (first, last) = explanation.tokens.unwrap();
+        assert_eq!(("7", 0, 1), (first.token.as_str(), first.start, first.end));
+        assert_eq!(("six", 6, 9), (last.token.as_str(), last.start, last.end));
+        assert_eq!(Some(76), explanation.value);
+        let explanation = explain_calibration_value("pqrst", &scanner);
+        assert_eq!(None, explanation.tokens);
+        assert_eq!(None, explanation.value);
+    }
+
+    /// Tests that the vocabulary selected in the solve context is used by the runner.
+    #[test]
+    fn test_day01_solve_context_vocabulary() {
+        let filename = input::write_temp_input("day01_vocabulary.txt", "twelve\nxsevenx\n");
+        let context = SolveContext::new(Vocabulary::english_extended());
+        let token = CancellationToken::new();
+        let report = runner::solve::<Day01>(&filename, Parts::Part2, &context, &token).unwrap();
+        assert_eq!(Some(Answer::Unsigned(89)), report.part2);
+        let report =
+            runner::solve::<Day01>(&filename, Parts::Part2, &SolveContext::default(), &token)
+                .unwrap();
+        assert_eq!(Some(Answer::Unsigned(77)), report.part2);
+    }
+}
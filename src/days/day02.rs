@@ -0,0 +1,394 @@
+use std::fmt;
+
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+use tracing::debug;
+
+use crate::cancel::CancellationToken;
+use crate::utils::input;
+use crate::{Answer, ParseError, Solution};
+
+const PROBLEM_NAME: &str = "Cube Conundrum";
+const PROBLEM_INPUT_FILE: &str = "./input/day02.txt";
+const PROBLEM_DAY: u64 = 2;
+
+/// Maximum number of red cubes allowed across a game in Part 1.
+pub const P1_MAX_RED: u64 = 12;
+/// Maximum number of green cubes allowed across a game in Part 1.
+pub const P1_MAX_GREEN: u64 = 13;
+/// Maximum number of blue cubes allowed across a game in Part 1.
+pub const P1_MAX_BLUE: u64 = 14;
+
+lazy_static! {
+    /// Matches the game ID at the start of an input file line
+    static ref REGEX_GAME: Regex = Regex::new(r"^Game (\d+): ").unwrap();
+    /// Matches the count and colour of a single entry in a cube group
+    static ref REGEX_CUBES: Regex = Regex::new(r"^(\d+) (red|green|blue)$").unwrap();
+}
+
+/// Number of red, blue and green cubes revealed in a single draw from the bag.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Draw {
+    pub red: u64,
+    pub blue: u64,
+    pub green: u64,
+}
+
+impl Draw {
+    /// Creates a new [`Draw`] with the given cube numbers.
+    pub fn new(red: u64, blue: u64, green: u64) -> Self {
+        Self { red, blue, green }
+    }
+
+    /// Checks if the draw is possible from a bag containing the maximum number of each colour.
+    pub fn check_draw(&self, max_red: u64, max_blue: u64, max_green: u64) -> bool {
+        self.red <= max_red && self.blue <= max_blue && self.green <= max_green
+    }
+}
+
+impl fmt::Display for Draw {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes = [
+            ("red", self.red),
+            ("green", self.green),
+            ("blue", self.blue),
+        ]
+        .iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(colour, count)| format!("{count} {colour}"))
+        .collect::<Vec<String>>();
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+/// Represents a game, with every draw made from the bag in the order they were made.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: u64,
+    pub draws: Vec<Draw>,
+}
+
+impl Game {
+    /// Finds the maximum number of each cube colour revealed across the draws of the game.
+    pub fn cube_max(&self) -> GameCubeMax {
+        self.draws
+            .iter()
+            .fold(GameCubeMax::new(0, 0, 0), |max, draw| {
+                GameCubeMax::new(
+                    max.red.max(draw.red),
+                    max.blue.max(draw.blue),
+                    max.green.max(draw.green),
+                )
+            })
+    }
+
+    /// Finds the first draw that is not possible from a bag containing the maximum number of each
+    /// colour, returning its index into the draws of the game. Returns None if the game is
+    /// possible.
+    pub fn find_impossible_draw(
+        &self,
+        max_red: u64,
+        max_blue: u64,
+        max_green: u64,
+    ) -> Option<(usize, &Draw)> {
+        self.draws
+            .iter()
+            .enumerate()
+            .find(|(_, draw)| !draw.check_draw(max_red, max_blue, max_green))
+    }
+}
+
+/// Represents the maximum number of red, blue and green cubes recorded across all groups for an
+/// individual game, derived from the game by [`Game::cube_max`]. Whether a game is possible is
+/// checked against its draws by [`Game::find_impossible_draw`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct GameCubeMax {
+    pub red: u64,
+    pub blue: u64,
+    pub green: u64,
+}
+
+impl GameCubeMax {
+    /// Creates a new [`GameCubeMax`] with the given cube numbers.
+    pub fn new(red: u64, blue: u64, green: u64) -> Self {
+        Self { red, blue, green }
+    }
+
+    /// Calculates the power of the game as a product of the maximum number of red, blue and green
+    /// cubes.
+    pub fn calculate_game_power(&self) -> u64 {
+        self.red * self.blue * self.green
+    }
+}
+
+/// Solver for AOC 2023 Day 02.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const PROBLEM_NAME: &'static str = PROBLEM_NAME;
+    const PROBLEM_INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PROBLEM_DAY: u64 = PROBLEM_DAY;
+
+    type Input = Vec<Game>;
+
+    fn parse(filename: &str) -> Result<Self::Input, ParseError> {
+        process_input_file(filename)
+    }
+
+    fn part1(input: &Self::Input, _token: &CancellationToken) -> Answer {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input, _token: &CancellationToken) -> Answer {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2023 Day 02 input file in the format required by the solver functions.
+///
+/// Returned value is vector of the games in the order they appear in the input file.
+pub fn process_input_file(filename: &str) -> Result<Vec<Game>, ParseError> {
+    // Read contents of problem input file
+    let raw_input = input::read_input(filename)?;
+    // Process input file contents into data structure
+    let mut games: Vec<Game> = vec![];
+    for (i, line) in raw_input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        games.push(convert_line_to_game(filename, i + 1, line)?);
+    }
+    Ok(games)
+}
+
+/// Converts an input file line into a game, keeping each of the cube groups as a draw.
+///
+/// Returns an error if the line is not in the format "Game <id>: <groups>", where the groups are
+/// separated by ';' and each group is a comma-separated list of "<count> <colour>" entries with
+/// each colour appearing at most once.
+fn convert_line_to_game(filename: &str, line_num: usize, s: &str) -> Result<Game, ParseError> {
+    // Extract game ID
+    let Ok(Some(game_match)) = REGEX_GAME.captures(s) else {
+        return Err(ParseError::unexpected(
+            filename,
+            line_num,
+            1,
+            "\"Game <id>: \" prefix",
+        ));
+    };
+    let id_match = game_match.get(1).unwrap();
+    let game_id = id_match.as_str().parse::<u64>().map_err(|_| {
+        ParseError::unexpected_at(filename, line_num, s, id_match.start(), "valid game ID")
+    })?;
+    // Extract number of red, blue and green cubes in each draw
+    let mut draws: Vec<Draw> = vec![];
+    let mut offset = game_match.get(0).unwrap().end();
+    for group in s[offset..].split(';') {
+        let mut draw = Draw::default();
+        let mut colours_seen: Vec<&str> = vec![];
+        for entry in group.split(',') {
+            let entry_offset = offset + (entry.len() - entry.trim_start().len());
+            let Ok(Some(caps)) = REGEX_CUBES.captures(entry.trim()) else {
+                return Err(ParseError::unexpected_at(
+                    filename,
+                    line_num,
+                    s,
+                    entry_offset,
+                    "cube count in format \"<count> <red|green|blue>\"",
+                ));
+            };
+            let count = caps[1].parse::<u64>().map_err(|_| {
+                ParseError::unexpected_at(filename, line_num, s, entry_offset, "valid cube count")
+            })?;
+            let colour = caps.get(2).unwrap().as_str();
+            if colours_seen.contains(&colour) {
+                return Err(ParseError::unexpected_at(
+                    filename,
+                    line_num,
+                    s,
+                    entry_offset,
+                    format!("{colour} cubes counted once per draw"),
+                ));
+            }
+            colours_seen.push(colour);
+            match colour {
+                "red" => draw.red = count,
+                "blue" => draw.blue = count,
+                _ => draw.green = count,
+            }
+            offset += entry.len() + 1;
+        }
+        draws.push(draw);
+    }
+    Ok(Game { id: game_id, draws })
+}
+
+/// Finds the games that are not possible from a bag containing the maximum number of each colour,
+/// along with the index and contents of the first impossible draw of each game.
+pub fn find_impossible_games(
+    games: &[Game],
+    max_red: u64,
+    max_blue: u64,
+    max_green: u64,
+) -> Vec<(&Game, usize, &Draw)> {
+    games
+        .iter()
+        .filter_map(|game| {
+            let (i, draw) = game.find_impossible_draw(max_red, max_blue, max_green)?;
+            Some((game, i, draw))
+        })
+        .collect()
+}
+
+/// Solves AOC 2023 Day 02 Part 1.
+///
+/// Determines the sum of the game IDs for the games that are possible, given a bag containing 12
+/// red, 13 green and 14 blue cubes. The first impossible draw of each rejected game is reported as
+/// a debug event.
+pub fn solve_part1(games: &[Game]) -> Answer {
+    let impossible_games = find_impossible_games(games, P1_MAX_RED, P1_MAX_BLUE, P1_MAX_GREEN);
+    for (game, i, draw) in &impossible_games {
+        debug!(game = game.id, draw = i + 1, cubes = %draw, "impossible game");
+    }
+    let id_sum = games.iter().map(|game| game.id).sum::<u64>();
+    let impossible_id_sum = impossible_games
+        .iter()
+        .map(|(game, _, _)| game.id)
+        .sum::<u64>();
+    (id_sum - impossible_id_sum).into()
+}
+
+/// Solves AOC 2023 Day 02 Part 2.
+///
+/// Determines the sum of the power for each game.
+///
+/// The power of a game is calculated by finding the product of the minimum number of red, blue and
+/// green cubes that would be required to make the game possible.
+pub fn solve_part2(games: &[Game]) -> Answer {
+    games
+        .iter()
+        .map(|game| game.cube_max().calculate_game_power())
+        .sum::<u64>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::answers;
+
+    /// Tests the Day 02 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day02_part1_actual() {
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part1(&input);
+        answers::assert_answer(PROBLEM_DAY, 1, &filename, solution);
+    }
+
+    /// Tests the Day 02 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day02_part2_actual() {
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part2(&input);
+        answers::assert_answer(PROBLEM_DAY, 2, &filename, solution);
+    }
+
+    /// Tests that every draw of a game is kept, and that the impossible draw is found.
+    #[test]
+    fn test_day02_game_draws() {
+        let game = convert_line_to_game(
+            "day02.txt",
+            1,
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+        )
+        .unwrap();
+        assert_eq!(3, game.id);
+        assert_eq!(
+            vec![Draw::new(20, 6, 8), Draw::new(4, 5, 13), Draw::new(1, 0, 5)],
+            game.draws
+        );
+        assert_eq!(GameCubeMax::new(20, 6, 13), game.cube_max());
+        let (i, draw) = game
+            .find_impossible_draw(P1_MAX_RED, P1_MAX_BLUE, P1_MAX_GREEN)
+            .unwrap();
+        assert_eq!(
+            (0, "20 red, 8 green, 6 blue"),
+            (i, draw.to_string().as_str())
+        );
+        assert_eq!(None, game.find_impossible_draw(20, 20, 20));
+    }
+
+    /// Tests that the impossible games are found with their first impossible draw.
+    #[test]
+    fn test_day02_find_impossible_games() {
+        let games = [
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+            "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, line)| convert_line_to_game("day02.txt", i + 1, line).unwrap())
+        .collect::<Vec<Game>>();
+        let impossible = find_impossible_games(&games, P1_MAX_RED, P1_MAX_BLUE, P1_MAX_GREEN)
+            .iter()
+            .map(|(game, i, draw)| (game.id, *i, draw.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (3, 0, String::from("20 red, 8 green, 6 blue")),
+                (4, 2, String::from("14 red, 3 green, 15 blue"))
+            ],
+            impossible
+        );
+        assert_eq!(Answer::Unsigned(1), solve_part1(&games));
+    }
+
+    /// Tests that a colour repeated within a single draw is reported as a parse error instead of
+    /// being summed.
+    #[test]
+    fn test_day02_input_repeated_colour() {
+        let filename = input::write_temp_input(
+            "day02_repeated_colour.txt",
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 red\n",
+        );
+        let err = process_input_file(&filename).unwrap_err();
+        assert_eq!(
+            format!("{filename}:1:40: expected red cubes counted once per draw"),
+            err.to_string()
+        );
+    }
+
+    /// Tests that a game containing a malformed cube group is reported as a parse error.
+    #[test]
+    fn test_day02_input_malformed_group() {
+        let filename = input::write_temp_input(
+            "day02_malformed.txt",
+            "Game 1: 3 blue, 4 red\nGame 2: 1 red, 2 purple; 3 green\n",
+        );
+        let err = process_input_file(&filename).unwrap_err();
+        assert_eq!(
+            format!("{filename}:2:16: expected cube count in format \"<count> <red|green|blue>\""),
+            err.to_string()
+        );
+    }
+
+    /// Tests that a line without a game ID is reported as a parse error instead of being skipped.
+    #[test]
+    fn test_day02_input_missing_game_id() {
+        let filename = input::write_temp_input("day02_missing_id.txt", "3 blue, 4 red\n");
+        let err = process_input_file(&filename).unwrap_err();
+        assert_eq!(
+            format!("{filename}:1:1: expected \"Game <id>: \" prefix"),
+            err.to_string()
+        );
+    }
+}
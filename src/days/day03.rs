@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+
+use crate::cancel::CancellationToken;
+use crate::utils::cartography::Point2D;
+use crate::utils::input;
+use crate::{Answer, ParseError, Solution};
+
+const PROBLEM_NAME: &str = "Gear Ratios";
+const PROBLEM_INPUT_FILE: &str = "./input/day03.txt";
+const PROBLEM_DAY: u64 = 3;
+
+lazy_static! {
+    /// Matches any string containing one or more digits in sequence
+    static ref REGEX_NUMBER: Regex = Regex::new(r"(\d+)").unwrap();
+    /// Matches any single character that is not '.' or a digit
+    static ref REGEX_SYMBOL: Regex = Regex::new(r"([^\.\d])").unwrap();
+}
+
+/// Numbers found on each row of the engine schematic, and the locations of the symbols.
+pub type EngineSchematic = (Vec<Vec<Number>>, HashMap<Point2D, char>);
+
+/// Number found on a row of the engine schematic, spanning the columns from start to end
+/// (inclusive).
+#[derive(Copy, Clone)]
+pub struct Number {
+    pub value: u64,
+    pub start: usize,
+    pub end: usize,
+    pub counted: bool,
+}
+
+/// Solver for AOC 2023 Day 03.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const PROBLEM_NAME: &'static str = PROBLEM_NAME;
+    const PROBLEM_INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PROBLEM_DAY: u64 = PROBLEM_DAY;
+
+    type Input = EngineSchematic;
+
+    fn parse(filename: &str) -> Result<Self::Input, ParseError> {
+        process_input_file(filename)
+    }
+
+    fn part1(input: &Self::Input, _token: &CancellationToken) -> Answer {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input, _token: &CancellationToken) -> Answer {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2023 Day 03 input file in the format required by the solver functions.
+///
+/// Returned value is HashMap mapping locations to the component held at the location in the engine
+/// schematic.
+pub fn process_input_file(filename: &str) -> Result<EngineSchematic, ParseError> {
+    // Read contents of problem input file
+    let raw_input = input::read_input(filename)?;
+    // Process input file contents into data structure
+    let mut number_data: Vec<Vec<Number>> = vec![];
+    let mut symbol_locs: HashMap<Point2D, char> = HashMap::new();
+    for (y, (line_num, row)) in raw_input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .enumerate()
+    {
+        let mut current_row: Vec<Number> = vec![];
+        // Find numbers in current row
+        for number_match in REGEX_NUMBER.find_iter(row) {
+            let number_match = number_match.unwrap();
+            let value = number_match.as_str().parse::<u64>().map_err(|_| {
+                ParseError::unexpected_at(
+                    filename,
+                    line_num,
+                    row,
+                    number_match.start(),
+                    "part number within range of u64",
+                )
+            })?;
+            let number_comp = Number {
+                value,
+                start: number_match.start(),
+                end: number_match.end() - 1,
+                counted: false,
+            };
+            current_row.push(number_comp);
+        }
+        number_data.push(current_row);
+        // Find symbols in current row
+        for symbol_match in REGEX_SYMBOL.find_iter(row) {
+            let symbol_match = symbol_match.unwrap();
+            let loc = Point2D::new(
+                i64::try_from(symbol_match.start()).unwrap(),
+                i64::try_from(y).unwrap(),
+            );
+            let c = symbol_match.as_str().chars().next().unwrap();
+            symbol_locs.insert(loc, c);
+        }
+    }
+    Ok((number_data, symbol_locs))
+}
+
+/// Solves AOC 2023 Day 03 Part 1.
+///
+/// Add up the total of all part numbers from the engine schematic.
+pub fn solve_part1(input: &EngineSchematic) -> Answer {
+    let mut numbers = input.0.clone();
+    let symbol_locs = &input.1;
+    // Calculate part number sum
+    let mut part_sum = 0;
+    for loc in symbol_locs.keys() {
+        for s_loc in loc.get_surrounding_points() {
+            // Look for number in row of the surrounding point
+            let check_row = usize::try_from(s_loc.y()).unwrap();
+            let check_col = usize::try_from(s_loc.x()).unwrap();
+            for number in numbers[check_row].iter_mut() {
+                // Check if the number has already been counted
+                if number.counted {
+                    continue;
+                }
+                // Check if the number is adjacent to the symbol
+                if number.start <= check_col && number.end >= check_col {
+                    part_sum += number.value;
+                    number.counted = true;
+                }
+            }
+        }
+    }
+    Answer::from(part_sum)
+}
+
+/// Solves AOC 2023 Day 03 Part 2.
+///
+/// Finds the sum of all gear ratios in the engine schematic. Gear ratios are found by calculating
+/// the product of the two values adjacent to a '*' symbol, where only two values are adjacent to
+/// the symbol.
+pub fn solve_part2(input: &EngineSchematic) -> Answer {
+    let symbol_locs = &input.1;
+    // Calculate gear ratio sum
+    let mut gear_ratio_sum = 0;
+    'outer: for (&loc, &symbol) in symbol_locs {
+        // Skip any symbols that are not '*'
+        if symbol != '*' {
+            continue;
+        }
+        // Refresh the state of numbers for each '*' symbol
+        let mut numbers = input.0.clone();
+        let mut part_numbers: Vec<u64> = vec![];
+        for s_loc in loc.get_surrounding_points() {
+            // Look for number in row of the surrounding point
+            let check_row = usize::try_from(s_loc.y()).unwrap();
+            let check_col = usize::try_from(s_loc.x()).unwrap();
+            for number in numbers[check_row].iter_mut() {
+                // Do not double-count numbers for the current '*' - independent of other '*'
+                if number.counted {
+                    continue;
+                }
+                // Check if part number is next to the '*' symbol
+                if number.start <= check_col && number.end >= check_col {
+                    number.counted = true;
+                    part_numbers.push(number.value);
+                }
+                // Check if too many part numbers are next to the '*' symbol
+                if part_numbers.len() > 2 {
+                    continue 'outer;
+                }
+            }
+        }
+        // Check if correct number of gear values is next to the symbol
+        if part_numbers.len() != 2 {
+            continue 'outer;
+        }
+        // Add the gear ratio for the current gear
+        gear_ratio_sum += part_numbers.iter().product::<u64>();
+    }
+    Answer::from(gear_ratio_sum)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::answers;
+
+    /// Tests the Day 03 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day03_part1_actual() {
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part1(&input);
+        answers::assert_answer(PROBLEM_DAY, 1, &filename, solution);
+    }
+
+    /// Tests the Day 03 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day03_part2_actual() {
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part2(&input);
+        answers::assert_answer(PROBLEM_DAY, 2, &filename, solution);
+    }
+}
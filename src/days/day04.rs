@@ -0,0 +1,222 @@
+use std::collections::{HashMap, HashSet};
+
+use fancy_regex::{Match, Regex};
+use lazy_static::lazy_static;
+
+use crate::cancel::CancellationToken;
+use crate::utils::input;
+use crate::{Answer, ParseError, Solution};
+
+const PROBLEM_NAME: &str = "Scratchcards";
+const PROBLEM_INPUT_FILE: &str = "./input/day04.txt";
+const PROBLEM_DAY: u64 = 4;
+
+lazy_static! {
+    static ref REGEX_CARD: Regex = Regex::new(r"^Card\s+(\d+): (.*) \| (.*)$").unwrap();
+}
+
+/// Solver for AOC 2023 Day 04.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const PROBLEM_NAME: &'static str = PROBLEM_NAME;
+    const PROBLEM_INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PROBLEM_DAY: u64 = PROBLEM_DAY;
+
+    type Input = HashMap<usize, usize>;
+
+    fn parse(filename: &str) -> Result<Self::Input, ParseError> {
+        process_input_file(filename)
+    }
+
+    fn part1(input: &Self::Input, _token: &CancellationToken) -> Answer {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input, _token: &CancellationToken) -> Answer {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2023 Day 04 input file in the format required by the solver functions.
+///
+/// Returned value is HashMap mapping card number to tuple of its winning numbers set and game
+/// numbers set.
+pub fn process_input_file(filename: &str) -> Result<HashMap<usize, usize>, ParseError> {
+    // Read contents of problem input file
+    let raw_input = input::read_input(filename)?;
+    // Process input file contents into data structure
+    let mut cards: HashMap<usize, usize> = HashMap::new();
+    for (i, line) in raw_input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (card_num, num_overlaps) = parse_input_file_line(filename, i + 1, line)?;
+        // Cards must be numbered in sequence for the copies to be tracked in Part 2
+        if card_num != cards.len() + 1 {
+            return Err(ParseError::unexpected_at(
+                filename,
+                i + 1,
+                line,
+                line.find(|c: char| c.is_ascii_digit()).unwrap_or(0),
+                format!("card number {}", cards.len() + 1),
+            ));
+        }
+        cards.insert(card_num, num_overlaps);
+    }
+    Ok(cards)
+}
+
+/// Parses a line from the input file into the format required for collection into a HashMap.
+///
+/// Returns an error if the line is not in the format "Card <n>: <winning numbers> | <numbers>".
+fn parse_input_file_line(
+    filename: &str,
+    line_num: usize,
+    s: &str,
+) -> Result<(usize, usize), ParseError> {
+    let Ok(Some(caps)) = REGEX_CARD.captures(s) else {
+        return Err(ParseError::unexpected(
+            filename,
+            line_num,
+            1,
+            "card in format \"Card <n>: <winning numbers> | <numbers>\"",
+        ));
+    };
+    let card_match = caps.get(1).unwrap();
+    let card_num = card_match.as_str().parse::<usize>().map_err(|_| {
+        ParseError::unexpected_at(
+            filename,
+            line_num,
+            s,
+            card_match.start(),
+            "valid card number",
+        )
+    })?;
+    let winning_nums = parse_number_set(filename, line_num, s, caps.get(2).unwrap())?;
+    let game_nums = parse_number_set(filename, line_num, s, caps.get(3).unwrap())?;
+    let num_overlaps = winning_nums.intersection(&game_nums).count();
+    Ok((card_num, num_overlaps))
+}
+
+/// Parses the matched section of the input file line into a [`HashSet`] containing the unique
+/// whitespace-separated numbers in the section.
+fn parse_number_set(
+    filename: &str,
+    line_num: usize,
+    line: &str,
+    section: Match,
+) -> Result<HashSet<u64>, ParseError> {
+    let mut nums: HashSet<u64> = HashSet::new();
+    let mut token_offset = section.start();
+    for token in section.as_str().split(|c: char| c.is_ascii_whitespace()) {
+        if !token.is_empty() {
+            let num = token.parse::<u64>().map_err(|_| {
+                ParseError::unexpected_at(filename, line_num, line, token_offset, "number")
+            })?;
+            nums.insert(num);
+        }
+        token_offset += token.len() + 1;
+    }
+    Ok(nums)
+}
+
+/// Solves AOC 2023 Day 04 Part 1.
+///
+/// Calculates the total number of points all cards are worth.
+pub fn solve_part1(cards: &HashMap<usize, usize>) -> Answer {
+    cards
+        .iter()
+        .map(|(_, &num_overlaps)| calculate_card_points(num_overlaps))
+        .sum::<u64>()
+        .into()
+}
+
+/// Solves AOC 2023 Day 04 Part 2.
+///
+/// Calculates the total number of scratchcards after checking all original and copied cards.
+pub fn solve_part2(cards: &HashMap<usize, usize>) -> Answer {
+    calculate_total_cards_processed(cards).into()
+}
+
+/// Calculates the number of points that the card is worth, based on how many of its game numbers
+/// are winning numbers. The points total is calculated as 2^(n-1), where n is the number of
+/// overlapping numbers.
+pub fn calculate_card_points(num_overlaps: usize) -> u64 {
+    if num_overlaps == 0 {
+        return 0;
+    }
+    2u64.pow(u32::try_from(num_overlaps).unwrap() - 1)
+}
+
+/// Calculates the total number of scratchcards processed, including all original and copied cards.
+pub fn calculate_total_cards_processed(cards: &HashMap<usize, usize>) -> u64 {
+    let mut cards_processed = 0;
+    let mut card_counts: Vec<u64> = vec![1; cards.len()];
+    for n in 0..cards.len() {
+        // Count the copies of the current card
+        cards_processed += card_counts[n];
+        let card_id = n + 1;
+        let winning_nums = *cards.get(&card_id).unwrap();
+        // Generate a copy of the following cards for each copy of current card
+        for delta in 1..=winning_nums {
+            if n + delta >= card_counts.len() {
+                break;
+            }
+            card_counts[n + delta] += card_counts[n];
+        }
+    }
+    cards_processed
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::answers;
+
+    /// Tests the Day 04 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day04_part1_actual() {
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part1(&input);
+        answers::assert_answer(PROBLEM_DAY, 1, &filename, solution);
+    }
+
+    /// Tests the Day 04 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day04_part2_actual() {
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part2(&input);
+        answers::assert_answer(PROBLEM_DAY, 2, &filename, solution);
+    }
+
+    /// Tests that a non-numeric card number entry is reported as a parse error.
+    #[test]
+    fn test_day04_input_malformed_number() {
+        let filename = input::write_temp_input(
+            "day04_malformed.txt",
+            "Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61 30\n",
+        );
+        let err = process_input_file(&filename).unwrap_err();
+        assert_eq!(format!("{filename}:2:12: expected number"), err.to_string());
+    }
+
+    /// Tests that a line that is not a card is reported as a parse error instead of being skipped.
+    #[test]
+    fn test_day04_input_missing_card() {
+        let filename =
+            input::write_temp_input("day04_missing_card.txt", "Card 1: 41 48 | 83 86\n41 48\n");
+        let err = process_input_file(&filename).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with(&format!("{filename}:2:1: expected card")));
+    }
+}
@@ -0,0 +1,419 @@
+use std::ops::RangeInclusive;
+
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+
+use crate::cancel::{self, CancellationToken};
+use crate::utils::input;
+use crate::{Answer, ParseError, Solution};
+
+const PROBLEM_NAME: &str = "If You Give A Seed A Fertilizer";
+const PROBLEM_INPUT_FILE: &str = "./input/day05.txt";
+const PROBLEM_DAY: u64 = 5;
+
+lazy_static! {
+    /// Matches space-separated seed value capture group from problem input file
+    static ref REGEX_SEEDS: Regex = Regex::new(r"^seeds: (.*)$").unwrap();
+    /// Matches against destination range start, source range start and length from input file maps
+    static ref REGEX_MAP_LINE: Regex = Regex::new(r"^(\d+) (\d+) (\d+)$").unwrap();
+}
+
+/// Combines the collection of ranges for mapping between source and destination values.
+#[derive(Debug)]
+pub struct RangeMap {
+    range_mappings: Vec<(RangeInclusive<usize>, RangeInclusive<usize>)>,
+}
+
+impl RangeMap {
+    /// Creates a new [`RangeMap`] from the given destination and source range pairs.
+    pub fn new(range_mappings: Vec<(RangeInclusive<usize>, RangeInclusive<usize>)>) -> Self {
+        Self { range_mappings }
+    }
+
+    /// Maps the given value from a source range to a destination value.
+    ///
+    /// Returns None if the given value does not fall within a source range for any of the range
+    /// mappings.
+    pub fn map_source_value_to_destination(&self, input_value: usize) -> usize {
+        for (dest_range, source_range) in &self.range_mappings {
+            // Check if the input value is mapped to a destination value
+            if source_range.contains(&input_value) {
+                let delta = input_value - source_range.start();
+                return dest_range.start() + delta;
+            }
+        }
+        // Input value was not covered by a range in the RangeMap, so it is returned unchanged
+        input_value
+    }
+
+    /// Maps the input range to destination ranges where covered by the source ranges. For the
+    /// components of the input range that are not covered by a source range, they are broken off
+    /// and added to the output vector.
+    pub fn map_source_range_to_destination_range(
+        &self,
+        input_range: &RangeInclusive<usize>,
+    ) -> Vec<RangeInclusive<usize>> {
+        let mut range_overlaps: Vec<RangeInclusive<usize>> = vec![];
+        for (dest_range, source_range) in &self.range_mappings {
+            // Check if source and input ranges do not overlap
+            if input_range.end() < source_range.start() || input_range.start() > source_range.end()
+            {
+                continue;
+            }
+            // Calculate start and end of the overlap
+            let overlap_start = input_range.start().max(source_range.start());
+            let overlap_end = input_range.end().min(source_range.end());
+            // Determine the mapped destination range
+            let delta = overlap_start - source_range.start();
+            let length = overlap_end - overlap_start;
+            let dest_start = dest_range.start() + delta;
+            let dest_end = dest_start + length;
+            range_overlaps.push(dest_start..=dest_end);
+            // Check if parts of the input range have not been mapped - on left and right of input
+            if input_range.start() < source_range.start() {
+                let left_start = *input_range.start();
+                let left_end = *source_range.start() - 1;
+                range_overlaps.push(left_start..=left_end);
+            }
+            if input_range.end() > source_range.end() {
+                let right_start = *source_range.end() + 1;
+                let right_end = *input_range.end();
+                range_overlaps.push(right_start..=right_end);
+            }
+            break;
+        }
+        // Input range mapped at destination if the input range is not covered by range map
+        if range_overlaps.is_empty() {
+            range_overlaps.push(input_range.clone());
+        }
+        range_overlaps
+    }
+}
+
+/// Solver for AOC 2023 Day 05.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const PROBLEM_NAME: &'static str = PROBLEM_NAME;
+    const PROBLEM_INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PROBLEM_DAY: u64 = PROBLEM_DAY;
+
+    type Input = (Vec<RangeInclusive<usize>>, Vec<RangeMap>);
+
+    fn parse(filename: &str) -> Result<Self::Input, ParseError> {
+        process_input_file(filename)
+    }
+
+    fn part1(input: &Self::Input, token: &CancellationToken) -> Answer {
+        solve_part1(input, token)
+    }
+
+    fn part2(input: &Self::Input, token: &CancellationToken) -> Answer {
+        solve_part2(input, token)
+    }
+}
+
+/// Processes the AOC 2023 Day 05 input file in the format required by the solver functions.
+///
+/// Returned value is tuple containing seed values and range maps given in the input file.
+pub fn process_input_file(
+    filename: &str,
+) -> Result<(Vec<RangeInclusive<usize>>, Vec<RangeMap>), ParseError> {
+    // Read contents of problem input file
+    let raw_input = input::read_input(filename)?;
+    let mut lines = raw_input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim_end()))
+        .filter(|(_, line)| !line.is_empty());
+    // Extract seed values - treat as range start and length value pairs
+    let seeds_caps = lines
+        .next()
+        .and_then(|(line_num, line)| match REGEX_SEEDS.captures(line) {
+            Ok(Some(caps)) => Some((line_num, line, caps)),
+            _ => None,
+        });
+    let Some((line_num, line, caps)) = seeds_caps else {
+        return Err(ParseError::unexpected(
+            filename,
+            1,
+            1,
+            "seeds in format \"seeds: <values>\"",
+        ));
+    };
+    let values_match = caps.get(1).unwrap();
+    let mut seed_values: Vec<(usize, usize)> = vec![];
+    let mut offset = values_match.start();
+    for token in values_match.as_str().split(' ') {
+        let value = parse_value(filename, line_num, line, offset, token)?;
+        seed_values.push((offset, value));
+        offset += token.len() + 1;
+    }
+    let seed_pairs = seed_values.chunks_exact(2);
+    if !seed_pairs.remainder().is_empty() {
+        return Err(ParseError::unexpected_at(
+            filename,
+            line_num,
+            line,
+            line.len(),
+            "seed range length",
+        ));
+    }
+    let mut seed_ranges: Vec<RangeInclusive<usize>> = vec![];
+    for pair in seed_pairs {
+        let (_, start) = pair[0];
+        let (length_offset, length) = pair[1];
+        if length == 0 {
+            return Err(ParseError::unexpected_at(
+                filename,
+                line_num,
+                line,
+                length_offset,
+                "non-zero seed range length",
+            ));
+        }
+        let Some(end) = start.checked_add(length - 1) else {
+            return Err(ParseError::unexpected_at(
+                filename,
+                line_num,
+                line,
+                length_offset,
+                "seed range length that does not overflow",
+            ));
+        };
+        seed_ranges.push(start..=end);
+    }
+    // Extract range mappings
+    let mut range_maps: Vec<RangeMap> = vec![];
+    let mut range_mappings: Option<Vec<(RangeInclusive<usize>, RangeInclusive<usize>)>> = None;
+    for (line_num, line) in lines {
+        // Start a new range map at each map header line
+        if line.ends_with("map:") {
+            if let Some(range_mappings) = range_mappings.take() {
+                range_maps.push(RangeMap::new(range_mappings));
+            }
+            range_mappings = Some(vec![]);
+            continue;
+        }
+        let Some(range_mappings) = range_mappings.as_mut() else {
+            return Err(ParseError::unexpected(
+                filename,
+                line_num,
+                1,
+                "map header in format \"<source>-to-<destination> map:\"",
+            ));
+        };
+        let Ok(Some(caps)) = REGEX_MAP_LINE.captures(line) else {
+            return Err(ParseError::unexpected(
+                filename,
+                line_num,
+                1,
+                "range in format \"<destination start> <source start> <length>\"",
+            ));
+        };
+        // Extract values from map line
+        let mut values = [0; 3];
+        for (i, value) in values.iter_mut().enumerate() {
+            let value_match = caps.get(i + 1).unwrap();
+            *value = parse_value(
+                filename,
+                line_num,
+                line,
+                value_match.start(),
+                value_match.as_str(),
+            )?;
+        }
+        let [dest_start, source_start, range_len] = values;
+        if range_len == 0 {
+            return Err(ParseError::unexpected_at(
+                filename,
+                line_num,
+                line,
+                caps.get(3).unwrap().start(),
+                "non-zero range length",
+            ));
+        }
+        // Create destination and source ranges
+        let (Some(dest_end), Some(source_end)) = (
+            dest_start.checked_add(range_len - 1),
+            source_start.checked_add(range_len - 1),
+        ) else {
+            return Err(ParseError::unexpected_at(
+                filename,
+                line_num,
+                line,
+                caps.get(3).unwrap().start(),
+                "range length that does not overflow",
+            ));
+        };
+        range_mappings.push((dest_start..=dest_end, source_start..=source_end));
+    }
+    if let Some(range_mappings) = range_mappings {
+        range_maps.push(RangeMap::new(range_mappings));
+    }
+    Ok((seed_ranges, range_maps))
+}
+
+/// Parses the token found at the given byte offset into the input file line as a value.
+fn parse_value(
+    filename: &str,
+    line_num: usize,
+    line: &str,
+    offset: usize,
+    token: &str,
+) -> Result<usize, ParseError> {
+    token
+        .parse::<usize>()
+        .map_err(|_| ParseError::unexpected_at(filename, line_num, line, offset, "number"))
+}
+
+/// Solves AOC 2023 Day 05 Part 1.
+///
+/// Determines the lowest location value corresponding to an initial seed value.
+pub fn solve_part1(
+    input: &(Vec<RangeInclusive<usize>>, Vec<RangeMap>),
+    token: &CancellationToken,
+) -> Answer {
+    let (seed_ranges, range_maps) = input;
+    // Extract the seed values from ranges used in Part 2
+    let seeds = seed_ranges
+        .iter()
+        .flat_map(|range| [*range.start(), *range.end() - *range.start() + 1])
+        .collect::<Vec<usize>>();
+    let mut lowest_location: Option<usize> = None;
+    // Consider each seed value individually for determining lowest location
+    for seed in seeds {
+        if token.is_cancelled() {
+            return cancel::cancelled_answer();
+        }
+        let mut value = seed;
+        // Map the seed value through to its location value
+        for range_map in range_maps {
+            value = range_map.map_source_value_to_destination(value);
+        }
+        if lowest_location.is_none() || lowest_location.unwrap() > value {
+            lowest_location = Some(value);
+        }
+    }
+    lowest_location.unwrap().into()
+}
+
+/// Solves AOC 2023 Day 05 Part 2.
+///
+/// Determines the lowest location value corresponding to an initial seed value, where the input
+/// seed value line is treated as specifying ranges of values.
+pub fn solve_part2(
+    input: &(Vec<RangeInclusive<usize>>, Vec<RangeMap>),
+    token: &CancellationToken,
+) -> Answer {
+    let (seed_ranges, range_maps) = input;
+    let mut lowest_location: Option<usize> = None;
+    // Find the lowest location value for each seed value range, and overall lowest location value
+    for seed_range in seed_ranges {
+        // Map the seed value range through to range/s of location values
+        let mut dest_ranges = vec![seed_range.clone()];
+        for range_map in range_maps {
+            if token.is_cancelled() {
+                return cancel::cancelled_answer();
+            }
+            // Get all of the ranges that the current ranges mapped to in the current range map
+            let mut new_ranges: Vec<RangeInclusive<usize>> = vec![];
+            for range in dest_ranges {
+                let output = range_map.map_source_range_to_destination_range(&range);
+                new_ranges.extend(output);
+            }
+            dest_ranges = new_ranges;
+        }
+        // Calculate the lowest location value for the starting seed value range
+        let run_lowest_location = *dest_ranges.iter().map(|range| range.start()).min().unwrap();
+        // Check if a new lowest location value has been found
+        if lowest_location.is_none() || lowest_location.unwrap() > run_lowest_location {
+            lowest_location = Some(run_lowest_location);
+        }
+    }
+    lowest_location.unwrap().into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::answers;
+
+    /// Tests the Day 05 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day05_part1_actual() {
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part1(&input, &CancellationToken::new());
+        answers::assert_answer(PROBLEM_DAY, 1, &filename, solution);
+    }
+
+    /// Tests the Day 05 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day05_part2_actual() {
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part2(&input, &CancellationToken::new());
+        answers::assert_answer(PROBLEM_DAY, 2, &filename, solution);
+    }
+
+    /// Tests that a map line with a missing value is reported as a parse error.
+    #[test]
+    fn test_day05_input_malformed_map_line() {
+        let filename = input::write_temp_input(
+            "day05_malformed.txt",
+            "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50\n",
+        );
+        let err = process_input_file(&filename).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with(&format!("{filename}:5:1: expected range")));
+    }
+
+    /// Tests that the range mapping stops once the cancellation token is cancelled.
+    #[test]
+    fn test_day05_part2_cancelled() {
+        let input = process_input_file("./input/test/day05_01.txt").unwrap();
+        let token = CancellationToken::new();
+        token.cancel();
+        assert_eq!(cancel::cancelled_answer(), solve_part2(&input, &token));
+    }
+
+    /// Tests that an odd number of seed values is reported as a parse error.
+    #[test]
+    fn test_day05_input_odd_seed_values() {
+        let filename = input::write_temp_input("day05_odd_seeds.txt", "seeds: 79 14 55\n");
+        let err = process_input_file(&filename).unwrap_err();
+        assert_eq!(
+            format!("{filename}:1:16: expected seed range length"),
+            err.to_string()
+        );
+    }
+
+    /// Tests that seed and map ranges ending past the largest value are reported as parse errors.
+    #[test]
+    fn test_day05_input_range_overflow() {
+        let filename =
+            input::write_temp_input("day05_seed_overflow.txt", "seeds: 18446744073709551615 2\n");
+        let err = process_input_file(&filename).unwrap_err();
+        assert_eq!(
+            format!("{filename}:1:29: expected seed range length that does not overflow"),
+            err.to_string()
+        );
+        let filename = input::write_temp_input(
+            "day05_map_overflow.txt",
+            "seeds: 79 14\n\nseed-to-soil map:\n50 18446744073709551615 2\n",
+        );
+        let err = process_input_file(&filename).unwrap_err();
+        assert_eq!(
+            format!("{filename}:4:25: expected range length that does not overflow"),
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use tracing::debug;
+
+use crate::cancel::{self, CancellationToken};
+use crate::utils::input;
+use crate::{Answer, ParseError, Solution};
+
+const PROBLEM_NAME: &str = "Wait For It";
+const PROBLEM_INPUT_FILE: &str = "./input/day06.txt";
+const PROBLEM_DAY: u64 = 6;
+
+/// Number of ways of attempting a race that are checked between checks of the cancellation token.
+const CANCEL_CHECK_INTERVAL: u64 = 1 << 16;
+
+/// Solver for AOC 2023 Day 06.
+pub struct Day06;
+
+/// Race times and best distances read from the input file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RaceRecords {
+    pub times: Vec<u64>,
+    pub distances: Vec<u64>,
+    /// Time of the single race given by combining the digits of the race times left to right.
+    pub combined_time: u64,
+    /// Best distance of the single race given by combining the digits of the best distances.
+    pub combined_distance: u64,
+}
+
+impl Solution for Day06 {
+    const PROBLEM_NAME: &'static str = PROBLEM_NAME;
+    const PROBLEM_INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PROBLEM_DAY: u64 = PROBLEM_DAY;
+
+    type Input = RaceRecords;
+
+    fn parse(filename: &str) -> Result<Self::Input, ParseError> {
+        process_input_file(filename)
+    }
+
+    fn part1(input: &Self::Input, token: &CancellationToken) -> Answer {
+        solve_part1(input, token)
+    }
+
+    fn part2(input: &Self::Input, token: &CancellationToken) -> Answer {
+        solve_part2(input, token)
+    }
+}
+
+/// Processes the AOC 2023 Day 06 input file in the format required by the solver functions.
+///
+/// Returned value is the race times and best distances for the races, along with the time and
+/// best distance of the combined race.
+pub fn process_input_file(filename: &str) -> Result<RaceRecords, ParseError> {
+    // Read contents of problem input file
+    let raw_input = input::read_input(filename)?;
+    // Process input file contents into data structure
+    let mut lines = raw_input.lines();
+    let times = parse_labelled_values(filename, 1, lines.next(), "Time:")?;
+    let distances = parse_labelled_values(filename, 2, lines.next(), "Distance:")?;
+    if times.len() != distances.len() {
+        return Err(ParseError::unexpected(
+            filename,
+            2,
+            1,
+            format!("{} distances to match the race times", times.len()),
+        ));
+    }
+    let combined_time = combine_values(filename, 1, &times, "Time:")?;
+    let combined_distance = combine_values(filename, 2, &distances, "Distance:")?;
+    Ok(RaceRecords {
+        times,
+        distances,
+        combined_time,
+        combined_distance,
+    })
+}
+
+/// Combines the digits of the values from the input file line left to right into a single value.
+fn combine_values(
+    filename: &str,
+    line_num: usize,
+    values: &[u64],
+    label: &str,
+) -> Result<u64, ParseError> {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<String>()
+        .parse::<u64>()
+        .map_err(|_| {
+            ParseError::unexpected(
+                filename,
+                line_num,
+                label.len() + 1,
+                "values that combine into a 64-bit number",
+            )
+        })
+}
+
+/// Parses the whitespace-separated values following the label at the start of the input file
+/// line.
+fn parse_labelled_values(
+    filename: &str,
+    line_num: usize,
+    line: Option<&str>,
+    label: &str,
+) -> Result<Vec<u64>, ParseError> {
+    let Some(values) = line.and_then(|line| line.strip_prefix(label)) else {
+        return Err(ParseError::unexpected(
+            filename,
+            line_num,
+            1,
+            format!("line starting with \"{label}\""),
+        ));
+    };
+    let line = line.unwrap();
+    let mut offset = label.len();
+    let mut output: Vec<u64> = vec![];
+    for token in values.split(|c: char| c.is_ascii_whitespace()) {
+        if !token.is_empty() {
+            let value = token.parse::<u64>().map_err(|_| {
+                ParseError::unexpected_at(filename, line_num, line, offset, "number")
+            })?;
+            output.push(value);
+        }
+        offset += token.len() + 1;
+    }
+    Ok(output)
+}
+
+/// Solves AOC 2023 Day 06 Part 1.
+///
+/// Determines the product of the number of ways the best distance for each race can be beaten.
+pub fn solve_part1(input: &RaceRecords, token: &CancellationToken) -> Answer {
+    input
+        .times
+        .iter()
+        .zip(input.distances.iter())
+        .map(|(&t_race, &d_best)| {
+            let ways = calculate_num_ways_to_beat_best_distance(t_race, d_best, token)?;
+            debug!(t_race, d_best, ways, "race");
+            Some(ways)
+        })
+        .product::<Option<usize>>()
+        .map_or_else(cancel::cancelled_answer, Answer::from)
+}
+
+/// Solves AOC 2023 Day 06 Part 2.
+///
+/// Calculates the number of ways to beat the best distance for the race, with the times and
+/// distances combined into a single value (digits combined left to right and parsed as a single
+/// integer).
+pub fn solve_part2(input: &RaceRecords, token: &CancellationToken) -> Answer {
+    let (t_race, d_best) = (input.combined_time, input.combined_distance);
+    let Some(ways) = calculate_num_ways_to_beat_best_distance(t_race, d_best, token) else {
+        return cancel::cancelled_answer();
+    };
+    debug!(t_race, d_best, ways, "race");
+    ways.into()
+}
+
+/// Calculates the number of ways to beat the best distance for a race of the specified duration (in
+/// milliseconds).
+///
+/// Returns None if the cancellation token is cancelled before the calculation is complete.
+pub fn calculate_num_ways_to_beat_best_distance(
+    t_race: u64,
+    d_best: u64,
+    token: &CancellationToken,
+) -> Option<usize> {
+    // Consider each possible way of attempting the race - charging boat for different periods. The
+    // distance for charging time t is the same as for t_race - t, so the winning ways are the
+    // charging times from the first winning way to its mirror at the other end of the race.
+    for t_charge in 0..=t_race / 2 {
+        if t_charge % CANCEL_CHECK_INTERVAL == 0 && token.is_cancelled() {
+            return None;
+        }
+        // Calculate the time that boat has to run and how far it will run before end of race. The
+        // distance can exceed 64 bits for long races.
+        let t_run = t_race - t_charge;
+        let d_run = u128::from(t_run) * u128::from(t_charge);
+        if d_run > u128::from(d_best) {
+            return Some((t_race - 2 * t_charge + 1) as usize);
+        }
+    }
+    Some(0)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::answers;
+    use crate::runner::{self, Parts, Phase};
+    use crate::solution::SolveContext;
+
+    /// Tests the Day 06 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day06_part1_actual() {
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part1(&input, &CancellationToken::new());
+        answers::assert_answer(PROBLEM_DAY, 1, &filename, solution);
+    }
+
+    /// Tests the Day 06 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day06_part2_actual() {
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part2(&input, &CancellationToken::new());
+        answers::assert_answer(PROBLEM_DAY, 2, &filename, solution);
+    }
+
+    /// Tests that a missing distance line is reported as a parse error.
+    #[test]
+    fn test_day06_input_missing_distances() {
+        let filename = input::write_temp_input("day06_missing.txt", "Time:      7  15   30\n");
+        let err = process_input_file(&filename).unwrap_err();
+        assert_eq!(
+            format!("{filename}:2:1: expected line starting with \"Distance:\""),
+            err.to_string()
+        );
+    }
+
+    /// Tests that race times too long to combine into a single race are reported as a parse error.
+    #[test]
+    fn test_day06_input_combined_overflow() {
+        let filename = input::write_temp_input(
+            "day06_combined_overflow.txt",
+            "Time:      18446744073 709551616\nDistance:  9 40\n",
+        );
+        let err = process_input_file(&filename).unwrap_err();
+        assert_eq!(
+            format!("{filename}:1:6: expected values that combine into a 64-bit number"),
+            err.to_string()
+        );
+    }
+
+    /// Tests that the ways to beat the best distance are counted for a race whose distances exceed
+    /// 64 bits.
+    #[test]
+    fn test_day06_num_ways_long_race() {
+        let token = CancellationToken::new();
+        assert_eq!(
+            Some(usize::MAX - 3),
+            calculate_num_ways_to_beat_best_distance(u64::MAX, u64::MAX, &token)
+        );
+        assert_eq!(
+            Some(4),
+            calculate_num_ways_to_beat_best_distance(7, 9, &token)
+        );
+        assert_eq!(
+            Some(0),
+            calculate_num_ways_to_beat_best_distance(4, 4, &token)
+        );
+    }
+
+    /// Tests that a long race calculation stops once the cancellation token is cancelled, and the
+    /// part is reported as timed out. The first winning way is over a billion charge times into the
+    /// race, and the distances stay within 64 bits.
+    #[test]
+    fn test_day06_part1_cancelled() {
+        let filename = input::write_temp_input(
+            "day06_long_race.txt",
+            "Time:      4000000000\nDistance:  3900000000000000000\n",
+        );
+        let token = CancellationToken::with_timeout(Duration::from_millis(50));
+        let start = Instant::now();
+        let context = SolveContext::default();
+        let report = runner::solve::<Day06>(&filename, Parts::Part1, &context, &token).unwrap();
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(Some(Phase::Part1), report.timeout);
+        assert_eq!(None, report.part1);
+    }
+}
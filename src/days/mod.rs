@@ -0,0 +1,20 @@
+use crate::runner::DaySolver;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+
+/// Returns the registry of solvers for all of the implemented problems, ordered by day.
+pub fn registry() -> Vec<DaySolver> {
+    vec![
+        DaySolver::new::<day01::Day01>(),
+        DaySolver::new::<day02::Day02>(),
+        DaySolver::new::<day03::Day03>(),
+        DaySolver::new::<day04::Day04>(),
+        DaySolver::new::<day05::Day05>(),
+        DaySolver::new::<day06::Day06>(),
+    ]
+}
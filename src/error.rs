@@ -0,0 +1,114 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Error raised when a problem input file cannot be read or is not in the expected format.
+#[derive(Debug)]
+pub struct ParseError {
+    file: String,
+    kind: ParseErrorKind,
+}
+
+/// Describes the cause of a [`ParseError`].
+#[derive(Debug)]
+pub enum ParseErrorKind {
+    /// The input file could not be read.
+    Io(io::Error),
+    /// The input file contents did not match the expected format at the given location. Line and
+    /// column numbers start at 1.
+    Unexpected {
+        line: usize,
+        column: usize,
+        expected: String,
+    },
+}
+
+impl ParseError {
+    /// Creates a new [`ParseError`] for an input file that could not be read.
+    pub fn io(file: &str, err: io::Error) -> Self {
+        Self {
+            file: file.to_string(),
+            kind: ParseErrorKind::Io(err),
+        }
+    }
+
+    /// Creates a new [`ParseError`] for input file contents that do not match the expected format
+    /// at the given line and column (both starting at 1).
+    pub fn unexpected(file: &str, line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            file: file.to_string(),
+            kind: ParseErrorKind::Unexpected {
+                line,
+                column,
+                expected: expected.into(),
+            },
+        }
+    }
+
+    /// Creates a new [`ParseError`] for input file contents that do not match the expected format,
+    /// with the column calculated from the byte offset into the given line of the input file.
+    pub fn unexpected_at(
+        file: &str,
+        line: usize,
+        line_text: &str,
+        offset: usize,
+        expected: impl Into<String>,
+    ) -> Self {
+        let column = line_text
+            .get(..offset)
+            .map_or(offset, |prefix| prefix.chars().count())
+            + 1;
+        Self::unexpected(file, line, column, expected)
+    }
+
+    /// Gets the name of the input file that caused the error.
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Gets the cause of the error.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::Io(err) => write!(f, "{}: unable to read input file: {err}", self.file),
+            ParseErrorKind::Unexpected {
+                line,
+                column,
+                expected,
+            } => write!(f, "{}:{line}:{column}: expected {expected}", self.file),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::Io(err) => Some(err),
+            ParseErrorKind::Unexpected { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the display format of errors for unexpected input file contents.
+    #[test]
+    fn test_parse_error_display_unexpected() {
+        let err = ParseError::unexpected("./input/day02.txt", 3, 9, "game ID");
+        assert_eq!("./input/day02.txt:3:9: expected game ID", err.to_string());
+    }
+
+    /// Tests that the column is calculated in characters from the byte offset into the line.
+    #[test]
+    fn test_parse_error_unexpected_at_column() {
+        let err = ParseError::unexpected_at("in.txt", 1, "né 12x", 6, "digit");
+        assert_eq!("in.txt:1:6: expected digit", err.to_string());
+    }
+}
@@ -0,0 +1,277 @@
+use std::fmt;
+use std::fs;
+
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+
+use crate::cancel::CancellationToken;
+use crate::runner::{DaySolver, Parts};
+use crate::solution::SolveContext;
+use crate::utils::input;
+use crate::ParseError;
+
+/// Directory containing the example input files, relative to the crate root.
+pub const EXAMPLES_DIR: &str = "./input/test";
+
+/// File extension of the files giving the expected answers for each example input file.
+pub const EXPECTED_ANSWERS_EXTENSION: &str = "answers";
+
+lazy_static! {
+    /// Matches the day and example number from the name of an example input file
+    static ref REGEX_EXAMPLE_FILE: Regex = Regex::new(r"^day(\d{2})_(\d{2})\.txt$").unwrap();
+}
+
+/// Example input file for a problem, together with the expected answers read from the ".answers"
+/// file alongside it.
+///
+/// The expected answers file contains a line in the format "<part> <answer>" for each part that
+/// the example applies to. Lines starting with '#' are ignored.
+#[derive(Clone, Debug)]
+pub struct Example {
+    pub day: u64,
+    pub number: u64,
+    pub input_file: String,
+    pub answers_file: String,
+    /// Expected answers by part number. None if the expected answers file does not exist.
+    pub expected: Option<Vec<(u8, String)>>,
+}
+
+/// Outcome of checking the solver for one part of a problem against an example.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExampleOutcome {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    Error(String),
+    MissingExpected,
+    /// The expected answers file has no answers recorded yet, so the example is skipped.
+    Unrecorded,
+}
+
+/// Result of checking the solver for one part of a problem against an example. The part is None
+/// for outcomes that apply to the example as a whole.
+#[derive(Clone, Debug)]
+pub struct ExampleResult {
+    pub day: u64,
+    pub number: u64,
+    pub part: Option<u8>,
+    pub outcome: ExampleOutcome,
+}
+
+impl ExampleResult {
+    /// Checks if the solver produced the expected answer.
+    pub fn is_pass(&self) -> bool {
+        self.outcome == ExampleOutcome::Pass
+    }
+
+    /// Checks if the example was skipped because no expected answers have been recorded.
+    pub fn is_skipped(&self) -> bool {
+        self.outcome == ExampleOutcome::Unrecorded
+    }
+}
+
+impl fmt::Display for ExampleResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {:02} example {:02}", self.day, self.number)?;
+        if let Some(part) = self.part {
+            write!(f, " part {part}")?;
+        }
+        match &self.outcome {
+            ExampleOutcome::Pass => write!(f, ": PASS"),
+            ExampleOutcome::Fail { expected, actual } => {
+                write!(f, ": FAIL (expected {expected}, got {actual})")
+            }
+            ExampleOutcome::Error(message) => write!(f, ": ERROR ({message})"),
+            ExampleOutcome::MissingExpected => {
+                write!(f, ": MISSING (no expected answers file)")
+            }
+            ExampleOutcome::Unrecorded => write!(f, ": SKIPPED (no expected answers recorded)"),
+        }
+    }
+}
+
+/// Finds all example input files named "dayNN_MM.txt" in the given directory, ordered by day and
+/// example number. Relative paths are resolved from the crate root.
+///
+/// Returns an error if the directory cannot be read, or if an expected answers file is malformed.
+pub fn discover_examples(dir: &str) -> Result<Vec<Example>, ParseError> {
+    let entries =
+        fs::read_dir(input::resolve_input_path(dir)).map_err(|err| ParseError::io(dir, err))?;
+    let mut examples: Vec<Example> = vec![];
+    for entry in entries {
+        let file_name = entry
+            .map_err(|err| ParseError::io(dir, err))?
+            .file_name()
+            .to_string_lossy()
+            .to_string();
+        let Ok(Some(caps)) = REGEX_EXAMPLE_FILE.captures(&file_name) else {
+            continue;
+        };
+        let day = caps[1].parse::<u64>().unwrap();
+        let number = caps[2].parse::<u64>().unwrap();
+        let input_file = format!("{dir}/{file_name}");
+        let answers_file = format!("{dir}/day{day:02}_{number:02}.{EXPECTED_ANSWERS_EXTENSION}");
+        let expected = if input::resolve_input_path(&answers_file).is_file() {
+            let raw_input = input::read_input(&answers_file)?;
+            Some(parse_expected_answers(&answers_file, &raw_input)?)
+        } else {
+            None
+        };
+        examples.push(Example {
+            day,
+            number,
+            input_file,
+            answers_file,
+            expected,
+        });
+    }
+    examples.sort_by_key(|example| (example.day, example.number));
+    Ok(examples)
+}
+
+/// Parses the contents of an expected answers file into the expected answer for each part.
+fn parse_expected_answers(
+    filename: &str,
+    raw_input: &str,
+) -> Result<Vec<(u8, String)>, ParseError> {
+    let mut expected: Vec<(u8, String)> = vec![];
+    for (i, line) in raw_input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let part_answer = line
+            .split_once(char::is_whitespace)
+            .map(|(part, answer)| (part.parse::<u8>().ok(), answer.trim()))
+            .filter(|(part, answer)| matches!(part, Some(1..=2)) && !answer.is_empty());
+        let Some((Some(part), answer)) = part_answer else {
+            return Err(ParseError::unexpected(
+                filename,
+                i + 1,
+                1,
+                "answer in format \"<part> <answer>\"",
+            ));
+        };
+        expected.push((part, answer.to_string()));
+    }
+    Ok(expected)
+}
+
+/// Checks the solver for the example's day against the example input, for each part with an
+/// expected answer.
+pub fn check_example(example: &Example, registry: &[DaySolver]) -> Vec<ExampleResult> {
+    let result = |part: Option<u8>, outcome: ExampleOutcome| ExampleResult {
+        day: example.day,
+        number: example.number,
+        part,
+        outcome,
+    };
+    let Some(expected) = example.expected.as_ref() else {
+        return vec![result(None, ExampleOutcome::MissingExpected)];
+    };
+    if expected.is_empty() {
+        return vec![result(None, ExampleOutcome::Unrecorded)];
+    }
+    let Some(solver) = registry.iter().find(|solver| solver.day() == example.day) else {
+        let message = format!("Day {} has not been implemented", example.day);
+        return vec![result(None, ExampleOutcome::Error(message))];
+    };
+    // Only solve the parts that the example applies to
+    let parts = match (
+        expected.iter().any(|(part, _)| *part == 1),
+        expected.iter().any(|(part, _)| *part == 2),
+    ) {
+        (true, false) => Parts::Part1,
+        (false, true) => Parts::Part2,
+        _ => Parts::Both,
+    };
+    let token = CancellationToken::new();
+    // Examples are solved with the default settings, as used by the puzzle descriptions
+    let context = SolveContext::default();
+    let report = match solver.solve(Some(&example.input_file), parts, &context, &token) {
+        Ok(report) => report,
+        Err(err) => return vec![result(None, ExampleOutcome::Error(err.to_string()))],
+    };
+    expected
+        .iter()
+        .map(|(part, expected)| {
+            let actual = match part {
+                1 => report.part1.as_ref(),
+                _ => report.part2.as_ref(),
+            };
+            let outcome = match actual {
+                Some(actual) if actual.matches(expected) => ExampleOutcome::Pass,
+                _ => ExampleOutcome::Fail {
+                    expected: expected.clone(),
+                    actual: actual.map(|a| a.to_string()).unwrap_or_default(),
+                },
+            };
+            result(Some(*part), outcome)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the expected answers are parsed for each part given in the file.
+    #[test]
+    fn test_parse_expected_answers() {
+        let expected = parse_expected_answers("day05_01.answers", "# Example\n1 35\n2 46\n");
+        assert_eq!(
+            vec![(1, String::from("35")), (2, String::from("46"))],
+            expected.unwrap()
+        );
+    }
+
+    /// Tests that a line with an invalid part number is reported as a parse error.
+    #[test]
+    fn test_parse_expected_answers_invalid_part() {
+        let err = parse_expected_answers("day05_01.answers", "1 35\n3 46\n").unwrap_err();
+        assert_eq!(
+            "day05_01.answers:2:1: expected answer in format \"<part> <answer>\"",
+            err.to_string()
+        );
+    }
+
+    /// Tests that the example input files are discovered in order of day and example number.
+    #[test]
+    fn test_discover_examples() {
+        let examples = discover_examples(EXAMPLES_DIR).unwrap();
+        let ids = examples
+            .iter()
+            .map(|example| (example.day, example.number))
+            .collect::<Vec<(u64, u64)>>();
+        let mut sorted_ids = ids.clone();
+        sorted_ids.sort();
+        assert!(ids.contains(&(5, 1)));
+        assert_eq!(sorted_ids, ids);
+    }
+
+    /// Tests that an example is skipped when its expected answers file has no answers recorded,
+    /// and reported as missing when there is no expected answers file.
+    #[test]
+    fn test_check_example_unrecorded() {
+        let mut example = Example {
+            day: 7,
+            number: 1,
+            input_file: String::from("./input/test/day07_01.txt"),
+            answers_file: String::from("./input/test/day07_01.answers"),
+            expected: Some(vec![]),
+        };
+        let results = check_example(&example, &[]);
+        assert_eq!(1, results.len());
+        assert!(results[0].is_skipped());
+        assert_eq!(
+            "Day 07 example 01: SKIPPED (no expected answers recorded)",
+            results[0].to_string()
+        );
+        example.expected = None;
+        let results = check_example(&example, &[]);
+        assert_eq!(ExampleOutcome::MissingExpected, results[0].outcome);
+        assert!(!results[0].is_skipped());
+    }
+}
@@ -0,0 +1,19 @@
+use std::io;
+
+use tracing::Level;
+
+/// Installs a global subscriber that prints the tracing spans and debug events emitted while
+/// solving to stderr, for the `--explain` option. Each event is prefixed by the phase and day it
+/// was emitted from.
+///
+/// Without a subscriber installed, the spans opened by the runner and the events emitted by the
+/// solvers are disabled at their call sites, so they do not slow down solving. Does nothing if a
+/// global subscriber has already been installed.
+pub fn install_subscriber() {
+    let _ = tracing_subscriber::fmt()
+        .with_max_level(Level::DEBUG)
+        .with_writer(io::stderr)
+        .with_target(false)
+        .without_time()
+        .try_init();
+}
@@ -0,0 +1,417 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use lazy_static::lazy_static;
+
+use crate::utils::{encryption, input};
+
+/// Environment variable giving the session cookie used to download the problem inputs.
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// Environment variable giving the base URL of the server to download the problem inputs from,
+/// used to test against a local server.
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+
+/// Base URL of the Advent of Code website.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable giving the location of the file recording the time of the last request,
+/// to use instead of the default.
+pub const RATE_LIMIT_FILE_ENV_VAR: &str = "AOC_RATE_LIMIT_FILE";
+
+/// Default location of the file recording the time of the last request, relative to the crate
+/// root.
+pub const DEFAULT_RATE_LIMIT_FILE: &str = "./.last_request";
+
+/// Minimum time between the start of consecutive requests to the server.
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+/// User agent sent with every request, identifying the tool as requested by the Advent of Code
+/// automation guidelines.
+const USER_AGENT: &str = concat!(
+    "aoc2023/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/cmooneycollett/aoc2023)"
+);
+
+/// Time allowed for a request to complete before it is abandoned.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+lazy_static! {
+    /// Time that the last request was started, shared by every fetcher in the process
+    static ref LAST_REQUEST: Mutex<Option<Instant>> = Mutex::new(None);
+}
+
+/// Error raised when a problem input cannot be downloaded.
+#[derive(Debug)]
+pub enum FetchError {
+    /// The session cookie environment variable is not set.
+    MissingSession,
+    /// The server responded with an error status.
+    Status { status: u16, day: u64 },
+    /// The request could not be sent or the response could not be read.
+    Transport(String),
+    /// The downloaded input could not be saved.
+    Io(io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSession => write!(
+                f,
+                "{SESSION_ENV_VAR} must be set to the session cookie to download inputs"
+            ),
+            Self::Status { status: 404, day } => {
+                write!(f, "input for Day {day:02} is not available yet (HTTP 404)")
+            }
+            Self::Status {
+                status: 400 | 401 | 403,
+                day,
+            } => write!(
+                f,
+                "unable to download input for Day {day:02}, check that {SESSION_ENV_VAR} is valid"
+            ),
+            Self::Status { status, day } => {
+                write!(
+                    f,
+                    "unable to download input for Day {day:02} (HTTP {status})"
+                )
+            }
+            Self::Transport(message) => write!(f, "request failed: {message}"),
+            Self::Io(err) => write!(f, "unable to save input: {err}"),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Result of fetching the input for a problem.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FetchOutcome {
+    /// The input file (or its encrypted copy) already existed, so no request was made.
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the problem inputs from the Advent of Code website (or a stand-in server), saving
+/// them to the input files.
+///
+/// Requests are rate limited to one every [`InputFetcher::min_interval`], across every fetcher in
+/// the process and (on a best effort basis) every process sharing the same rate limit file.
+/// Input files that already exist are never downloaded again.
+#[derive(Clone, Debug)]
+pub struct InputFetcher {
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    rate_limit_file: PathBuf,
+}
+
+impl InputFetcher {
+    /// Creates a new [`InputFetcher`] for the given server and session cookie.
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            min_interval: MIN_REQUEST_INTERVAL,
+            rate_limit_file: input::resolve_input_path(&rate_limit_file()),
+        }
+    }
+
+    /// Creates a new [`InputFetcher`] using the session cookie from the `AOC_SESSION` environment
+    /// variable, and the server from the `AOC_BASE_URL` environment variable if set.
+    ///
+    /// Returns an error if the session cookie is not set.
+    pub fn from_env() -> Result<Self, FetchError> {
+        let session = session_from_env().ok_or(FetchError::MissingSession)?;
+        Ok(Self::new(&base_url_from_env(), &session))
+    }
+
+    /// Sets the minimum time between the start of consecutive requests.
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Gets the minimum time between the start of consecutive requests.
+    pub fn min_interval(&self) -> Duration {
+        self.min_interval
+    }
+
+    /// Sets the file recording the time of the last request.
+    pub fn with_rate_limit_file(mut self, rate_limit_file: &Path) -> Self {
+        self.rate_limit_file = rate_limit_file.to_path_buf();
+        self
+    }
+
+    /// Saves the input for the given day to the given path, unless the file or its encrypted copy
+    /// already exists.
+    pub fn fetch_to(&self, day: u64, path: &Path) -> Result<FetchOutcome, FetchError> {
+        if path.exists() || encryption::encrypted_path(path).exists() {
+            return Ok(FetchOutcome::Cached);
+        }
+        let input = self.download(day)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Write to a temporary file first, so an interrupted write does not leave a partial input
+        let mut partial = path.as_os_str().to_os_string();
+        partial.push(".part");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, path)?;
+        Ok(FetchOutcome::Downloaded)
+    }
+
+    /// Downloads the input for the given day.
+    pub fn download(&self, day: u64) -> Result<String, FetchError> {
+        wait_for_rate_limit(self.min_interval, &self.rate_limit_file);
+        let url = format!("{}/2023/day/{day}/input", self.base_url);
+        let response = http_agent()
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|err| FetchError::Transport(err.to_string())),
+            Err(ureq::Error::Status(status, _)) => Err(FetchError::Status { status, day }),
+            Err(ureq::Error::Transport(err)) => Err(FetchError::Transport(err.to_string())),
+        }
+    }
+}
+
+/// Waits until the minimum interval has passed since the last request to the server was started,
+/// then records the time of the new request. Shared by every client that makes requests to the
+/// server.
+///
+/// The time of the last request is kept in the process and in the rate limit file, as the number
+/// of milliseconds since the Unix epoch, so that requests made by separate runs of the tool are
+/// also limited. The file is only a best effort across processes: it is not locked, so processes
+/// starting requests at the same moment can both go ahead, and requests are still limited within
+/// the process if the file cannot be read or written.
+pub(crate) fn wait_for_rate_limit(min_interval: Duration, rate_limit_file: &Path) {
+    let mut last_request = LAST_REQUEST.lock().unwrap_or_else(|err| err.into_inner());
+    let in_process_wait = last_request
+        .map(|last_request| min_interval.saturating_sub(last_request.elapsed()))
+        .unwrap_or_default();
+    let file_wait = read_last_request_time(rate_limit_file)
+        .and_then(|last_request| SystemTime::now().duration_since(last_request).ok())
+        .map(|elapsed| min_interval.saturating_sub(elapsed))
+        .unwrap_or_default();
+    thread::sleep(in_process_wait.max(file_wait));
+    *last_request = Some(Instant::now());
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    if let Some(parent) = rate_limit_file.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let _ = fs::write(rate_limit_file, format!("{now}\n"));
+}
+
+/// Reads the time of the last request from the rate limit file, or None if the file does not
+/// exist or is malformed.
+fn read_last_request_time(rate_limit_file: &Path) -> Option<SystemTime> {
+    let millis = fs::read_to_string(rate_limit_file)
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    UNIX_EPOCH.checked_add(Duration::from_millis(millis))
+}
+
+/// Returns the name of the rate limit file given by the `AOC_RATE_LIMIT_FILE` environment
+/// variable, or the default rate limit file if the variable is not set.
+pub fn rate_limit_file() -> String {
+    env::var(RATE_LIMIT_FILE_ENV_VAR)
+        .ok()
+        .filter(|filename| !filename.is_empty())
+        .unwrap_or_else(|| DEFAULT_RATE_LIMIT_FILE.to_string())
+}
+
+/// Creates an HTTP agent for making requests to the server, with a timeout and the user agent
+/// identifying this tool.
+pub(crate) fn http_agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .timeout(REQUEST_TIMEOUT)
+        .user_agent(USER_AGENT)
+        .build()
+}
+
+/// Gets the session cookie from the `AOC_SESSION` environment variable, or None if not set.
+pub(crate) fn session_from_env() -> Option<String> {
+    env::var(SESSION_ENV_VAR)
+        .ok()
+        .filter(|session| !session.trim().is_empty())
+}
+
+/// Gets the base URL of the server from the `AOC_BASE_URL` environment variable, or the Advent of
+/// Code website if not set.
+pub(crate) fn base_url_from_env() -> String {
+    env::var(BASE_URL_ENV_VAR)
+        .ok()
+        .filter(|url| !url.is_empty())
+        .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+}
+
+/// Downloads the missing problem input file for the given day, if a session cookie is set. Used
+/// by the runner when the default problem input file for a day does not exist.
+///
+/// Returns None if the input file or its encrypted copy exists, or no session cookie is set.
+pub fn fetch_missing_input(day: u64, path: &Path) -> Option<Result<FetchOutcome, FetchError>> {
+    if path.exists() || encryption::encrypted_path(path).exists() {
+        return None;
+    }
+    let fetcher = InputFetcher::from_env().ok()?;
+    eprintln!(
+        "[*] Downloading input for Day {day:02} to {}",
+        path.display()
+    );
+    Some(fetcher.fetch_to(day, path))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::utils::stub_server::{self, StubRequests};
+
+    /// Starts a stub server that answers each request with the input for the requested day, or
+    /// 404 for days after Day 06.
+    fn start_stub_server() -> (String, StubRequests) {
+        stub_server::start(|request| {
+            let day = request
+                .path()
+                .split('/')
+                .nth(3)
+                .and_then(|day| day.parse::<u64>().ok())
+                .unwrap_or(0);
+            if (1..=6).contains(&day) {
+                (200, format!("input for day {day}\n"))
+            } else {
+                (404, String::new())
+            }
+        })
+    }
+
+    /// Returns a path in a new temporary directory for a test input file.
+    fn temp_input_path(test: &str, name: &str) -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let dir = env::temp_dir().join(format!(
+            "aoc2023_fetch_{}_{test}_{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        dir.join(name)
+    }
+
+    /// Tests that the input is downloaded with the session cookie, and never downloaded again
+    /// once saved.
+    #[test]
+    fn test_fetch_to_cached() {
+        let (base_url, requests) = start_stub_server();
+        let path = temp_input_path("cached", "day03.txt");
+        let fetcher = InputFetcher::new(&base_url, "abc123")
+            .with_min_interval(Duration::ZERO)
+            .with_rate_limit_file(&path.with_file_name(".last_request"));
+        assert_eq!(
+            FetchOutcome::Downloaded,
+            fetcher.fetch_to(3, &path).unwrap()
+        );
+        assert_eq!(FetchOutcome::Cached, fetcher.fetch_to(3, &path).unwrap());
+        assert_eq!("input for day 3\n", fs::read_to_string(&path).unwrap());
+        let requests = requests.lock().unwrap();
+        assert_eq!(1, requests.len());
+        assert!(requests[0]
+            .head
+            .starts_with("GET /2023/day/3/input HTTP/1.1\r\n"));
+        assert!(requests[0].head.contains("session=abc123"));
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    /// Tests that error responses are reported and no input file is saved.
+    #[test]
+    fn test_fetch_to_not_found() {
+        let (base_url, _) = start_stub_server();
+        let path = temp_input_path("not_found", "day25.txt");
+        let fetcher = InputFetcher::new(&base_url, "abc123")
+            .with_min_interval(Duration::ZERO)
+            .with_rate_limit_file(&path.with_file_name(".last_request"));
+        let err = fetcher.fetch_to(25, &path).unwrap_err();
+        assert_eq!(
+            "input for Day 25 is not available yet (HTTP 404)",
+            err.to_string()
+        );
+        assert!(!path.exists());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    /// Tests that consecutive requests are spaced out by the minimum interval.
+    #[test]
+    fn test_download_rate_limited() {
+        let (base_url, requests) = start_stub_server();
+        let interval = Duration::from_millis(200);
+        let rate_limit_file = temp_input_path("rate_limited", ".last_request");
+        let fetcher = InputFetcher::new(&base_url, "abc123")
+            .with_min_interval(interval)
+            .with_rate_limit_file(&rate_limit_file);
+        let start = Instant::now();
+        fetcher.download(1).unwrap();
+        fetcher.download(2).unwrap();
+        assert!(start.elapsed() >= interval);
+        assert_eq!(2, requests.lock().unwrap().len());
+        fs::remove_dir_all(rate_limit_file.parent().unwrap()).unwrap();
+    }
+
+    /// Tests that a request recorded in the rate limit file, such as by another process, delays
+    /// the next request.
+    #[test]
+    fn test_download_rate_limited_by_file() {
+        let (base_url, requests) = start_stub_server();
+        let interval = Duration::from_millis(300);
+        let rate_limit_file = temp_input_path("rate_limit_file", ".last_request");
+        fs::create_dir_all(rate_limit_file.parent().unwrap()).unwrap();
+        let start = Instant::now();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+        fs::write(&rate_limit_file, format!("{now}\n")).unwrap();
+        let fetcher = InputFetcher::new(&base_url, "abc123")
+            .with_min_interval(interval)
+            .with_rate_limit_file(&rate_limit_file);
+        fetcher.download(1).unwrap();
+        // Allow for the recorded time being truncated to milliseconds
+        assert!(start.elapsed() + Duration::from_millis(1) >= interval);
+        assert_eq!(1, requests.lock().unwrap().len());
+        let last_request = read_last_request_time(&rate_limit_file).unwrap();
+        assert!(
+            last_request.duration_since(UNIX_EPOCH).unwrap().as_millis()
+                >= now + interval.as_millis()
+        );
+        fs::remove_dir_all(rate_limit_file.parent().unwrap()).unwrap();
+    }
+
+    /// Tests that an input file that already exists is not downloaded again.
+    #[test]
+    fn test_fetch_missing_input_exists() {
+        let path = input::resolve_input_path("./input/test/day06_01.txt");
+        assert!(fetch_missing_input(6, &path).is_none());
+    }
+}
@@ -1 +1,26 @@
+pub mod alloc_stats;
+pub mod answer;
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod calibration;
+pub mod cancel;
+pub mod check;
+pub mod cli;
+pub mod days;
+pub mod error;
+pub mod examples;
+pub mod explain;
+pub mod fetch;
+pub mod output;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
 pub mod utils;
+pub mod vocab;
+pub mod watch;
+
+pub use answer::Answer;
+pub use error::ParseError;
+pub use solution::{run_solution, Solution};
@@ -1,3 +1,577 @@
-fn main() {
-    println!("Hello, world! This is the default binary.");
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+use aoc2023::answers::{self, AnswerRegistry, Verdict};
+use aoc2023::calibration::CalibrationReport;
+use aoc2023::cancel::CancellationToken;
+use aoc2023::cli::{self, RunOptions, RUN_OPTIONS_USAGE};
+use aoc2023::days::{
+    self,
+    day01::{self, Day01},
+};
+use aoc2023::explain;
+use aoc2023::fetch::{FetchOutcome, InputFetcher};
+use aoc2023::output::OutputFormat;
+use aoc2023::runner::{self, DaySolver, Parts};
+use aoc2023::scaffold;
+use aoc2023::solution::SolveContext;
+use aoc2023::submit::{self, GuessHistory, GuessVerdict, HttpTransport, SubmitOutcome};
+use aoc2023::utils::encryption::{self, InputKey};
+use aoc2023::utils::input::{self, INPUT_DIR_ENV_VAR};
+use aoc2023::vocab::Vocabulary;
+use aoc2023::{check, watch, ParseError, Solution};
+
+/// Directory containing the problem input files, relative to the crate root.
+const DEFAULT_INPUT_DIR: &str = "./input";
+
+const USAGE: &str = "\
+Usage: aoc2023 <COMMAND>
+
+Commands:
+  run <DAY|all> [OPTIONS]    Solve the problem for the given day, or all implemented days
+  new-day <DAY> <TITLE>      Create the solver, binary and input files for a new day
+  verify <DAY|all>           Report whether each answer is correct, wrong or unknown, without
+                             revealing the expected answers
+  submit <DAY> <PART>        Solve the given part and submit the answer, unless earlier guesses
+                             show it cannot be correct (requires the AOC_SESSION session cookie)
+  hash-answers               Replace the plain text answers in the answers file with salted hashes
+  check <DAY>                Check the day's example and problem inputs against the expected answers
+  watch <DAY>                Re-run check for the day whenever its source or input files change
+  calibration [OPTIONS]      Explain the Day 01 calibration value found on each input line, taking
+                             the --part (default: 2), --input, --format (text or json) and
+                             --vocab run options
+  inputs <ACTION> [DIR]      Encrypt or decrypt the input files in DIR (default: ./input), where
+                             ACTION is encrypt, decrypt or keygen (create a new key file)
+  inputs fetch <DAY>         Download the input for the given day, unless already saved (requires
+                             the AOC_SESSION session cookie)
+
+Run options:";
+
+/// Subcommands supported by the runner.
+enum Command {
+    Run(RunArgs),
+    NewDay { day: u64, title: String },
+    Verify { days: DaySelection },
+    HashAnswers,
+    Submit { day: u64, part: u8 },
+    Check { day: u64 },
+    Watch { day: u64 },
+    Calibration(CalibrationArgs),
+    Inputs { action: InputsAction, dir: String },
+    FetchInput { day: u64 },
+}
+
+/// Actions performed on the input files by the inputs subcommand.
+enum InputsAction {
+    Encrypt,
+    Decrypt,
+    Keygen,
+}
+
+/// Arguments given to the calibration subcommand.
+struct CalibrationArgs {
+    part: u8,
+    input_file: Option<String>,
+    format: OutputFormat,
+    /// Vocabulary used to read number words for Part 2.
+    vocab: Vocabulary,
+}
+
+/// Days selected for solving by the run subcommand.
+enum DaySelection {
+    All,
+    Day(u64),
+}
+
+/// Arguments given to the run subcommand.
+struct RunArgs {
+    days: DaySelection,
+    options: RunOptions,
+}
+
+/// Runs the solvers for the AOC 2023 problems selected by the command line arguments. Solutions
+/// are printed to stdout, followed by a summary table of solutions and execution times.
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    match parse_args(&args) {
+        Ok(Command::Run(run_args)) => run(&run_args),
+        Ok(Command::NewDay { day, title }) => new_day(day, &title),
+        Ok(Command::Verify { days }) => verify(&days),
+        Ok(Command::HashAnswers) => hash_answers(),
+        Ok(Command::Submit { day, part }) => submit(day, part),
+        Ok(Command::Check { day }) => check(day),
+        Ok(Command::Watch { day }) => watch(day),
+        Ok(Command::Calibration(calibration_args)) => calibration(&calibration_args),
+        Ok(Command::Inputs { action, dir }) => inputs(&action, &dir),
+        Ok(Command::FetchInput { day }) => fetch_input(day),
+        Err(message) => {
+            eprintln!("[!] {message}\n\n{USAGE}\n{RUN_OPTIONS_USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Parses the command line arguments (excluding the program name) into the selected subcommand.
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    match args.next().map(|s| s.as_str()) {
+        Some("run") => {
+            let days = parse_day_selection(args.next(), "run")?;
+            let mut options = RunOptions::default();
+            while let Some(arg) = args.next() {
+                if !cli::parse_run_option(arg, &mut args, &mut options)? {
+                    return Err(format!("unexpected argument: {arg}"));
+                }
+            }
+            options.validate()?;
+            options.resolve_vocab()?;
+            if options.input_file.is_some() && matches!(days, DaySelection::All) {
+                return Err(String::from(
+                    "--input can only be used when running a single day",
+                ));
+            }
+            Ok(Command::Run(RunArgs { days, options }))
+        }
+        Some("new-day") => {
+            let day = match args.next() {
+                Some(day) => parse_day(day)?,
+                None => return Err(String::from("missing day for new-day command")),
+            };
+            let title = args.map(|s| s.as_str()).collect::<Vec<&str>>().join(" ");
+            if title.trim().is_empty() {
+                return Err(String::from("missing title for new-day command"));
+            }
+            Ok(Command::NewDay {
+                day,
+                title: title.trim().to_string(),
+            })
+        }
+        Some("verify") => {
+            let days = parse_day_selection(args.next(), "verify")?;
+            if let Some(arg) = args.next() {
+                return Err(format!("unexpected argument: {arg}"));
+            }
+            Ok(Command::Verify { days })
+        }
+        Some("submit") => {
+            let day = match args.next() {
+                Some(day) => parse_day(day)?,
+                None => return Err(String::from("missing day for submit command")),
+            };
+            let part = match args.next().map(|s| s.as_str()) {
+                Some("1") => 1,
+                Some("2") => 2,
+                Some(other) => return Err(format!("invalid part: {other}")),
+                None => return Err(String::from("missing part for submit command")),
+            };
+            if let Some(arg) = args.next() {
+                return Err(format!("unexpected argument: {arg}"));
+            }
+            Ok(Command::Submit { day, part })
+        }
+        Some("hash-answers") => {
+            if let Some(arg) = args.next() {
+                return Err(format!("unexpected argument: {arg}"));
+            }
+            Ok(Command::HashAnswers)
+        }
+        Some(command @ ("check" | "watch")) => {
+            let day = match args.next() {
+                Some(day) => parse_day(day)?,
+                None => return Err(format!("missing day for {command} command")),
+            };
+            if let Some(arg) = args.next() {
+                return Err(format!("unexpected argument: {arg}"));
+            }
+            match command {
+                "check" => Ok(Command::Check { day }),
+                _ => Ok(Command::Watch { day }),
+            }
+        }
+        Some("calibration") => {
+            let mut options = RunOptions {
+                parts: Parts::Part2,
+                ..RunOptions::default()
+            };
+            while let Some(arg) = args.next() {
+                let supported = matches!(
+                    arg.as_str(),
+                    "--part" | "-p" | "--input" | "-i" | "--format" | "-f" | "--vocab"
+                );
+                if !supported || !cli::parse_run_option(arg, &mut args, &mut options)? {
+                    return Err(format!("unexpected argument: {arg}"));
+                }
+            }
+            let part = match options.parts {
+                Parts::Part1 => 1,
+                _ => 2,
+            };
+            if options.format == OutputFormat::Csv {
+                return Err(String::from("calibration report cannot be printed as csv"));
+            }
+            options.resolve_vocab()?;
+            Ok(Command::Calibration(CalibrationArgs {
+                part,
+                input_file: options.input_file,
+                format: options.format,
+                vocab: options.vocab.unwrap_or_default(),
+            }))
+        }
+        Some("inputs") => {
+            let action = match args.next().map(|s| s.as_str()) {
+                Some("encrypt") => InputsAction::Encrypt,
+                Some("decrypt") => InputsAction::Decrypt,
+                Some("keygen") => InputsAction::Keygen,
+                Some("fetch") => {
+                    let day = match args.next() {
+                        Some(day) => parse_day(day)?,
+                        None => return Err(String::from("missing day for inputs fetch command")),
+                    };
+                    if let Some(arg) = args.next() {
+                        return Err(format!("unexpected argument: {arg}"));
+                    }
+                    return Ok(Command::FetchInput { day });
+                }
+                Some(other) => return Err(format!("invalid inputs action: {other}")),
+                None => return Err(String::from("missing action for inputs command")),
+            };
+            let dir = match (args.next(), env::var(INPUT_DIR_ENV_VAR)) {
+                (Some(dir), _) => dir.to_string(),
+                (None, Ok(dir)) if !dir.is_empty() => dir,
+                (None, _) => String::from(DEFAULT_INPUT_DIR),
+            };
+            if let Some(arg) = args.next() {
+                return Err(format!("unexpected argument: {arg}"));
+            }
+            Ok(Command::Inputs { action, dir })
+        }
+        Some(other) => Err(format!("unknown command: {other}")),
+        None => Err(String::from("missing command")),
+    }
+}
+
+/// Parses the days selected by the argument, which is either a day number or "all".
+fn parse_day_selection(arg: Option<&String>, command: &str) -> Result<DaySelection, String> {
+    match arg.map(|s| s.as_str()) {
+        Some("all") => Ok(DaySelection::All),
+        Some(day) => Ok(DaySelection::Day(parse_day(day)?)),
+        None => Err(format!("missing day for {command} command")),
+    }
+}
+
+/// Parses the day number from the given string.
+fn parse_day(s: &str) -> Result<u64, String> {
+    match s.parse::<u64>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day: {s}")),
+    }
+}
+
+/// Solves the selected days and parts, printing the results and a summary table to stdout.
+fn run(run_args: &RunArgs) -> ExitCode {
+    let Some(solvers) = select_solvers(&run_args.days) else {
+        return ExitCode::FAILURE;
+    };
+    if run_args.options.explain {
+        explain::install_subscriber();
+    }
+    let outcome = runner::run_solvers(&solvers, &run_args.options);
+    if !outcome.reports.is_empty() && run_args.options.format == OutputFormat::Text {
+        println!();
+        runner::print_summary(&outcome.reports);
+        runner::print_run_times(&outcome, run_args.options.jobs);
+    }
+    if outcome.failed {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+/// Selects the solvers for the selected days, printing an error to stderr and returning None if the
+/// selected day has not been implemented.
+fn select_solvers(days: &DaySelection) -> Option<Vec<DaySolver>> {
+    let registry = days::registry();
+    match days {
+        DaySelection::All => Some(registry),
+        DaySelection::Day(day) => {
+            let solvers = registry
+                .into_iter()
+                .filter(|solver| solver.day() == *day)
+                .collect::<Vec<DaySolver>>();
+            if solvers.is_empty() {
+                eprintln!("[!] Day {day} has not been implemented");
+                return None;
+            }
+            Some(solvers)
+        }
+    }
+}
+
+/// Solves the selected days using the problem input files, printing whether each answer is
+/// correct, wrong or unknown according to the answers file. Expected answers are never printed.
+fn verify(days: &DaySelection) -> ExitCode {
+    let Some(solvers) = select_solvers(days) else {
+        return ExitCode::FAILURE;
+    };
+    let registry = match AnswerRegistry::load() {
+        Ok(registry) => registry,
+        Err(err) => {
+            eprintln!("[!] {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut failed = false;
+    for solver in &solvers {
+        match check::verify_input(solver, &registry) {
+            Ok(verifications) => {
+                for verification in verifications {
+                    let status = match verification.verdict {
+                        Verdict::Correct => "+",
+                        Verdict::Wrong => "!",
+                        Verdict::Unknown => "*",
+                    };
+                    failed |= verification.verdict == Verdict::Wrong;
+                    println!("[{status}] {verification}");
+                }
+            }
+            Err(err) => {
+                eprintln!("[!] AOC 2023 Day {} - {err}", solver.day());
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+/// Solves the given part using the problem input file and submits the answer, printing the
+/// verdict. Every verdict is recorded in the guess history, which is used to refuse answers that
+/// cannot be correct.
+fn submit(day: u64, part: u8) -> ExitCode {
+    let Some(solvers) = select_solvers(&DaySelection::Day(day)) else {
+        return ExitCode::FAILURE;
+    };
+    let parts = if part == 1 {
+        Parts::Part1
+    } else {
+        Parts::Part2
+    };
+    let context = SolveContext::default();
+    let answer = match solvers[0].solve(None, parts, &context, &CancellationToken::new()) {
+        Ok(report) if part == 1 => report.part1,
+        Ok(report) => report.part2,
+        Err(err) => {
+            eprintln!("[!] AOC 2023 Day {day} - {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(answer) = answer else {
+        eprintln!("[!] No answer found for Day {day:02} Part {part}");
+        return ExitCode::FAILURE;
+    };
+    let mut history = match GuessHistory::load() {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("[!] {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("[*] Submitting Day {day:02} Part {part} answer: {answer}");
+    let result = HttpTransport::from_env()
+        .and_then(|transport| submit::submit(&transport, &mut history, day, part, &answer));
+    match result {
+        Ok(SubmitOutcome::Guess(GuessVerdict::Correct)) => {
+            println!("[+] Answer is correct");
+            return ExitCode::SUCCESS;
+        }
+        Ok(SubmitOutcome::Guess(verdict)) => eprintln!("[!] Answer is {verdict}"),
+        Ok(SubmitOutcome::RateLimited { wait }) => match wait {
+            Some(wait) => eprintln!(
+                "[!] Answer submitted too recently, wait {}s before trying again",
+                wait.as_secs()
+            ),
+            None => eprintln!("[!] Answer submitted too recently, wait before trying again"),
+        },
+        Ok(SubmitOutcome::AlreadySolved) => {
+            eprintln!("[!] Day {day:02} Part {part} is already solved or not available yet")
+        }
+        Ok(SubmitOutcome::Unrecognised) => eprintln!("[!] Unrecognised response from the server"),
+        Err(err) => eprintln!("[!] {err}"),
+    }
+    ExitCode::FAILURE
+}
+
+/// Replaces the plain text answers in the answers file with salted hashes of the answers.
+fn hash_answers() -> ExitCode {
+    let filename = answers::answers_file();
+    let result = input::read_input(&filename)
+        .and_then(|raw_input| answers::hash_answers(&filename, &raw_input))
+        .and_then(|(output, hashed)| {
+            fs::write(input::resolve_input_path(&filename), output)
+                .map(|_| hashed)
+                .map_err(|err| ParseError::io(&filename, err))
+        });
+    match result {
+        Ok(hashed) => {
+            println!("[+] Hashed {hashed} answer(s) in {filename}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("[!] {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Creates the files for a new day from the template, printing the files created to stdout.
+fn new_day(day: u64, title: &str) -> ExitCode {
+    match scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), day, title) {
+        Ok(files) => {
+            for file in files {
+                println!("[+] Created {}", file.display());
+            }
+            println!(
+                "[+] Registered Day {day:02} in {}",
+                scaffold::DAYS_MODULE_FILE
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("[!] Unable to create Day {day:02} - {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Checks the given day against its example inputs and the problem input file, printing a compact
+/// pass/fail line for each check to stdout.
+fn check(day: u64) -> ExitCode {
+    match check::check_day(day, &days::registry()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("[!] {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints the report explaining the Day 01 calibration value found on each line of the input, for
+/// finding which tokens were picked when an answer is wrong.
+fn calibration(calibration_args: &CalibrationArgs) -> ExitCode {
+    let filename = input::select_input_file(
+        Day01::PROBLEM_DAY,
+        Day01::PROBLEM_INPUT_FILE,
+        calibration_args.input_file.as_deref(),
+    );
+    let scanner = match calibration_args.part {
+        1 => day01::DIGIT_SCANNER.clone(),
+        _ => calibration_args.vocab.scanner(),
+    };
+    let raw_input = match input::read_input(&filename) {
+        Ok(raw_input) => raw_input,
+        Err(err) => {
+            eprintln!("[!] {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let report = CalibrationReport::new(calibration_args.part, &raw_input, &scanner);
+    match calibration_args.format {
+        OutputFormat::Json => print!("{}", report.format_json()),
+        _ => print!("{}", report.format_text()),
+    }
+    ExitCode::SUCCESS
+}
+
+/// Watches the source and input files for the given day, re-checking the day whenever they change.
+fn watch(day: u64) -> ExitCode {
+    match days::registry()
+        .into_iter()
+        .find(|solver| solver.day() == day)
+    {
+        Some(solver) => {
+            watch::watch_day(&solver);
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("[!] Day {day} has not been implemented");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Encrypts or decrypts the input files in the given directory, or creates a new key file,
+/// printing the files written to stdout.
+fn inputs(action: &InputsAction, dir: &str) -> ExitCode {
+    if let InputsAction::Keygen = action {
+        let filename = encryption::key_file();
+        let path = input::resolve_input_path(&filename);
+        if path.exists() {
+            eprintln!("[!] Input key file {filename} already exists");
+            return ExitCode::FAILURE;
+        }
+        if let Err(err) = fs::write(&path, format!("{}\n", InputKey::generate().to_hex())) {
+            eprintln!("[!] Unable to write input key file {filename} - {err}");
+            return ExitCode::FAILURE;
+        }
+        println!("[+] Created input key file {filename}, keep it out of version control");
+        return ExitCode::SUCCESS;
+    }
+    let key = match InputKey::load() {
+        Ok(key) => key,
+        Err(err) => {
+            eprintln!("[!] {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let path = input::resolve_input_path(dir);
+    let result = match action {
+        InputsAction::Encrypt => encryption::encrypt_dir(&path, &key),
+        _ => encryption::decrypt_dir(&path, &key),
+    };
+    match result {
+        Ok(files) => {
+            for (from, to) in &files {
+                let name = |path: &Path| path.file_name().unwrap_or_default().to_owned();
+                println!(
+                    "[+] {} -> {}",
+                    name(from).to_string_lossy(),
+                    name(to).to_string_lossy()
+                );
+            }
+            println!("[*] {} file(s) written in {dir}", files.len());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("[!] Unable to process input files in {dir} - {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Downloads the input for the given day to its input file, unless the file has already been
+/// saved.
+fn fetch_input(day: u64) -> ExitCode {
+    let filename =
+        input::select_input_file(day, &format!("{DEFAULT_INPUT_DIR}/day{day:02}.txt"), None);
+    let result = InputFetcher::from_env()
+        .and_then(|fetcher| fetcher.fetch_to(day, &input::resolve_input_path(&filename)));
+    match result {
+        Ok(FetchOutcome::Downloaded) => {
+            println!("[+] Downloaded input for Day {day:02} to {filename}");
+            ExitCode::SUCCESS
+        }
+        Ok(FetchOutcome::Cached) => {
+            println!("[*] Input for Day {day:02} already saved to {filename}, not downloading");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("[!] {err}");
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -0,0 +1,258 @@
+use std::fmt::Write as _;
+
+use crate::runner::{DayReport, Phase};
+
+/// Column names for the records in CSV output, also used as the field names in JSON output.
+const RECORD_FIELDS: [&str; 9] = [
+    "day",
+    "name",
+    "part",
+    "status",
+    "answer",
+    "answer_type",
+    "input_ns",
+    "part_ns",
+    "total_ns",
+];
+
+/// Format used to print the solutions and execution times.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable report for each problem, followed by a summary table.
+    Text,
+    /// JSON array with one record for each part run, or for the problem if no part was run.
+    Json,
+    /// CSV with a header row and one record for each part run, or for the problem if no part was
+    /// run.
+    Csv,
+}
+
+impl OutputFormat {
+    /// Gets the output format with the given name, if there is one.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            "csv" => Some(OutputFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Value of a single field in an output record.
+enum Field {
+    Number(u128),
+    Text(String),
+    /// Missing value, given as null in JSON output and left empty in CSV output.
+    Null,
+}
+
+/// Converts the reports into records with the fields given in [`RECORD_FIELDS`]. Durations are
+/// given in nanoseconds.
+///
+/// Each part that was solved or timed out gets a record with the status "ok" or "timeout". A problem
+/// with no such part (because its input could not be read, the solver panicked or the input timed
+/// out) gets a single record with the status of the problem and no part, answer or durations, so
+/// every problem that was run appears in the output.
+fn records(reports: &[DayReport]) -> Vec<Vec<Field>> {
+    let mut records: Vec<Vec<Field>> = vec![];
+    for report in reports {
+        let parts = [
+            (1, Phase::Part1, &report.part1, report.part1_duration),
+            (2, Phase::Part2, &report.part2, report.part2_duration),
+        ];
+        let mut has_part_record = false;
+        for (part, phase, answer, duration) in parts {
+            let [status, answer, answer_type, part_ns] = match (answer, duration) {
+                (Some(answer), Some(duration)) => [
+                    Field::Text(String::from("ok")),
+                    Field::Text(answer.to_string()),
+                    Field::Text(answer.kind().to_string()),
+                    Field::Number(duration.as_nanos()),
+                ],
+                _ if report.timeout == Some(phase) => [
+                    Field::Text(String::from("timeout")),
+                    Field::Null,
+                    Field::Null,
+                    Field::Null,
+                ],
+                _ => continue,
+            };
+            has_part_record = true;
+            records.push(vec![
+                Field::Number(report.day as u128),
+                Field::Text(report.name.to_string()),
+                Field::Number(part),
+                status,
+                answer,
+                answer_type,
+                Field::Number(report.input_duration.as_nanos()),
+                part_ns,
+                Field::Number(report.total_duration().as_nanos()),
+            ]);
+        }
+        if !has_part_record {
+            records.push(vec![
+                Field::Number(report.day as u128),
+                Field::Text(report.name.to_string()),
+                Field::Null,
+                Field::Text(report.status().to_string()),
+                Field::Null,
+                Field::Null,
+                Field::Null,
+                Field::Null,
+                Field::Null,
+            ]);
+        }
+    }
+    records
+}
+
+/// Formats the solutions and execution times as a JSON array of records. Answers are given as
+/// strings so that large numbers are not rounded by JSON parsers.
+pub fn format_json(reports: &[DayReport]) -> String {
+    let records = records(reports)
+        .iter()
+        .map(|record| {
+            let fields = RECORD_FIELDS
+                .iter()
+                .zip(record.iter())
+                .map(|(name, field)| match field {
+                    Field::Number(n) => format!("\"{name}\": {n}"),
+                    Field::Text(s) => format!("\"{name}\": {}", escape_json(s)),
+                    Field::Null => format!("\"{name}\": null"),
+                })
+                .collect::<Vec<String>>();
+            format!("  {{{}}}", fields.join(", "))
+        })
+        .collect::<Vec<String>>();
+    if records.is_empty() {
+        return String::from("[]\n");
+    }
+    format!("[\n{}\n]\n", records.join(",\n"))
+}
+
+/// Formats the solutions and execution times as CSV, with a header row.
+pub fn format_csv(reports: &[DayReport]) -> String {
+    let mut output = RECORD_FIELDS.join(",");
+    output.push('\n');
+    for record in records(reports) {
+        let fields = record
+            .iter()
+            .map(|field| match field {
+                Field::Number(n) => n.to_string(),
+                Field::Text(s) => escape_csv(s),
+                Field::Null => String::new(),
+            })
+            .collect::<Vec<String>>();
+        writeln!(output, "{}", fields.join(",")).unwrap();
+    }
+    output
+}
+
+/// Quotes the string for use as a JSON string value.
+pub(crate) fn escape_json(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Quotes the string for use as a CSV field, if it contains any special characters.
+fn escape_csv(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::ParseError;
+
+    /// Creates a report for Day 06 with only Part 2 solved.
+    fn report() -> DayReport {
+        DayReport {
+            input_duration: Duration::from_nanos(100),
+            part2_duration: Some(Duration::from_nanos(250)),
+            ..DayReport::for_test(6, [None, Some(71503)])
+        }
+    }
+
+    /// Tests the JSON output format, with a record for each solved part.
+    #[test]
+    fn test_format_json() {
+        assert_eq!(
+            "[\n  {\"day\": 6, \"name\": \"Wait For It\", \"part\": 2, \"status\": \"ok\", \
+             \"answer\": \"71503\", \"answer_type\": \"u64\", \"input_ns\": 100, \
+             \"part_ns\": 250, \"total_ns\": 350}\n]\n",
+            format_json(&[report()])
+        );
+        assert_eq!("[]\n", format_json(&[]));
+    }
+
+    /// Tests the CSV output format, with a header row and a record for each solved part.
+    #[test]
+    fn test_format_csv() {
+        assert_eq!(
+            "day,name,part,status,answer,answer_type,input_ns,part_ns,total_ns\n\
+             6,Wait For It,2,ok,71503,u64,100,250,350\n",
+            format_csv(&[report()])
+        );
+    }
+
+    /// Tests that a part that timed out gets a record with no answer or part duration.
+    #[test]
+    fn test_format_csv_timeout() {
+        let report = DayReport {
+            timeout: Some(Phase::Part1),
+            ..report()
+        };
+        assert_eq!(
+            "day,name,part,status,answer,answer_type,input_ns,part_ns,total_ns\n\
+             6,Wait For It,1,timeout,,,100,,350\n\
+             6,Wait For It,2,ok,71503,u64,100,250,350\n",
+            format_csv(&[report])
+        );
+    }
+
+    /// Tests that a problem that panicked or whose input could not be read still gets a record, with
+    /// the status of the problem.
+    #[test]
+    fn test_format_json_failed() {
+        let panicked = DayReport::panicked(6, "Wait For It", "oops");
+        let err = ParseError::unexpected("./input/day07.txt", 1, 1, "hand");
+        let failed = DayReport::input_error(7, "Camel Cards", &err);
+        assert_eq!(
+            "[\n  {\"day\": 6, \"name\": \"Wait For It\", \"part\": null, \"status\": \"panic\", \
+             \"answer\": null, \"answer_type\": null, \"input_ns\": null, \"part_ns\": null, \
+             \"total_ns\": null},\n  \
+             {\"day\": 7, \"name\": \"Camel Cards\", \"part\": null, \"status\": \"error\", \
+             \"answer\": null, \"answer_type\": null, \"input_ns\": null, \"part_ns\": null, \
+             \"total_ns\": null}\n]\n",
+            format_json(&[panicked, failed])
+        );
+    }
+
+    /// Tests that special characters are escaped in JSON and CSV fields.
+    #[test]
+    fn test_escape_fields() {
+        assert_eq!("\"a \\\"b\\\"\\n\"", escape_json("a \"b\"\n"));
+        assert_eq!("\"a, \"\"b\"\"\"", escape_csv("a, \"b\""));
+        assert_eq!("abc", escape_csv("abc"));
+    }
+}
@@ -0,0 +1,797 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tracing::debug_span;
+
+use crate::alloc_stats::{self, AllocStats};
+use crate::baseline::{Baseline, Regression};
+use crate::bench::{self, BenchReport};
+use crate::cancel::CancellationToken;
+use crate::cli::RunOptions;
+use crate::fetch;
+use crate::output::{self, OutputFormat};
+use crate::solution::SolveContext;
+use crate::utils::input;
+use crate::{Answer, ParseError, Solution};
+
+/// Selects which parts of a problem are solved by a run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parts {
+    Both,
+    Part1,
+    Part2,
+}
+
+impl Parts {
+    /// Checks if Part 1 is included in the selection.
+    pub fn includes_part1(&self) -> bool {
+        matches!(self, Parts::Both | Parts::Part1)
+    }
+
+    /// Checks if Part 2 is included in the selection.
+    pub fn includes_part2(&self) -> bool {
+        matches!(self, Parts::Both | Parts::Part2)
+    }
+}
+
+/// Timed phases of solving a problem.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Input,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    /// Gets the label used for the phase in human-readable output.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Phase::Input => "Input",
+            Phase::Part1 => "Part 1",
+            Phase::Part2 => "Part 2",
+        }
+    }
+
+    /// Gets the key used for the phase in machine-readable output.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Phase::Input => "input",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+
+    /// Gets the phase with the given key, if there is one.
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "input" => Some(Phase::Input),
+            "part1" => Some(Phase::Part1),
+            "part2" => Some(Phase::Part2),
+            _ => None,
+        }
+    }
+}
+
+/// Solutions and execution times recorded from solving a single problem.
+///
+/// Solutions and durations are None for parts that were not included in the run, or that were not
+/// completed within the time budget.
+#[derive(Clone, Debug)]
+pub struct DayReport {
+    pub day: u64,
+    pub name: &'static str,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub input_duration: Duration,
+    pub part1_duration: Option<Duration>,
+    pub part2_duration: Option<Duration>,
+    /// Phase that was running when the time budget ran out, if the problem timed out.
+    pub timeout: Option<Phase>,
+    /// Panic message, if the solver panicked. No phases are recorded for a problem that panicked.
+    pub panic: Option<String>,
+    /// Error message, if the input file could not be read or parsed. No phases are recorded for a
+    /// problem with an input error.
+    pub error: Option<String>,
+    /// Heap allocation statistics for each phase. Only recorded if the `alloc-stats` feature is
+    /// enabled.
+    pub alloc_stats: Vec<(Phase, AllocStats)>,
+}
+
+impl DayReport {
+    /// Creates a report for a problem that panicked while being solved.
+    pub fn panicked(day: u64, name: &'static str, message: &str) -> Self {
+        Self {
+            day,
+            name,
+            part1: None,
+            part2: None,
+            input_duration: Duration::ZERO,
+            part1_duration: None,
+            part2_duration: None,
+            timeout: None,
+            panic: Some(message.to_string()),
+            error: None,
+            alloc_stats: vec![],
+        }
+    }
+
+    /// Creates a report for a problem whose input file could not be read or parsed.
+    pub fn input_error(day: u64, name: &'static str, err: &ParseError) -> Self {
+        Self {
+            panic: None,
+            error: Some(err.to_string()),
+            ..Self::panicked(day, name, "")
+        }
+    }
+
+    /// Checks if every phase included in the run was completed, without timing out, panicking or
+    /// failing to read the input.
+    pub fn completed(&self) -> bool {
+        self.timeout.is_none() && self.panic.is_none() && self.error.is_none()
+    }
+
+    /// Gets the status of the problem used in machine-readable output: "ok", "timeout", "panic" or
+    /// "error" (the input could not be read or parsed).
+    pub fn status(&self) -> &'static str {
+        match (&self.panic, &self.error, self.timeout) {
+            (Some(_), _, _) => "panic",
+            (_, Some(_), _) => "error",
+            (_, _, Some(_)) => "timeout",
+            _ => "ok",
+        }
+    }
+
+    /// Calculates the total execution time across input processing and the solved parts.
+    pub fn total_duration(&self) -> Duration {
+        self.input_duration
+            + self.part1_duration.unwrap_or_default()
+            + self.part2_duration.unwrap_or_default()
+    }
+
+    /// Gets the execution time of each phase included in the run.
+    pub fn phase_durations(&self) -> Vec<(Phase, Duration)> {
+        let mut durations = vec![(Phase::Input, self.input_duration)];
+        if let Some(duration) = self.part1_duration {
+            durations.push((Phase::Part1, duration));
+        }
+        if let Some(duration) = self.part2_duration {
+            durations.push((Phase::Part2, duration));
+        }
+        durations
+    }
+}
+
+#[cfg(test)]
+impl DayReport {
+    /// Creates a report for the given day with the given Part 1 and Part 2 answers, used as a
+    /// fixture in tests. Parts with an answer are given a zero duration, and parts without an
+    /// answer are treated as not included in the run.
+    pub(crate) fn for_test(day: u64, answers: [Option<u64>; 2]) -> Self {
+        let name = crate::days::registry()
+            .iter()
+            .find(|solver| solver.day() == day)
+            .map_or("Test", |solver| solver.name());
+        let [part1, part2] = answers.map(|answer| answer.map(Answer::Unsigned));
+        Self {
+            day,
+            name,
+            part1_duration: part1.as_ref().map(|_| Duration::ZERO),
+            part2_duration: part2.as_ref().map(|_| Duration::ZERO),
+            part1,
+            part2,
+            input_duration: Duration::ZERO,
+            timeout: None,
+            panic: None,
+            error: None,
+            alloc_stats: vec![],
+        }
+    }
+}
+
+/// Results of running the solvers for a set of problems.
+#[derive(Clone, Debug)]
+pub struct RunOutcome {
+    /// Reports for the problems that were solved, in the order of the solvers.
+    pub reports: Vec<DayReport>,
+    /// Whether any of the problems failed or regressed against the baseline.
+    pub failed: bool,
+    /// Elapsed time from the start to the end of the run.
+    pub wall_time: Duration,
+    /// Elapsed time of each task that solved a problem, summed across all worker threads. This is
+    /// not CPU time, as it includes time the worker threads spent blocked or descheduled.
+    pub task_time: Duration,
+}
+
+/// Type-erased entry in the registry of problem solvers, allowing the solvers for different days
+/// to be held in the same collection.
+#[derive(Clone, Copy)]
+pub struct DaySolver {
+    day: u64,
+    name: &'static str,
+    input_file: &'static str,
+    solve: fn(&str, Parts, &SolveContext, &CancellationToken) -> Result<DayReport, ParseError>,
+    bench: fn(&str, Parts, &SolveContext, usize) -> Result<BenchReport, ParseError>,
+}
+
+impl DaySolver {
+    /// Creates a new [`DaySolver`] for the given [`Solution`].
+    pub fn new<S: Solution>() -> Self {
+        Self {
+            day: S::PROBLEM_DAY,
+            name: S::PROBLEM_NAME,
+            input_file: S::PROBLEM_INPUT_FILE,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+
+    /// Gets the day number of the problem.
+    pub fn day(&self) -> u64 {
+        self.day
+    }
+
+    /// Gets the name of the problem.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Selects the input file for the problem. The given input file is used if provided,
+    /// otherwise the input file is selected from the `AOC_INPUT_DIR` directory or the default
+    /// problem input file.
+    pub fn select_input_file(&self, input_file: Option<&str>) -> String {
+        input::select_input_file(self.day, self.input_file, input_file)
+    }
+
+    /// Downloads the default problem input file if it is missing and the `AOC_SESSION` environment
+    /// variable is set. Input files given as an argument or read from the `AOC_INPUT_DIR`
+    /// directory are never downloaded.
+    fn fetch_missing_input(&self, input_file: Option<&str>, filename: &str) {
+        if input_file.is_some() || filename != self.input_file || !(1..=25).contains(&self.day) {
+            return;
+        }
+        let path = input::resolve_input_path(filename);
+        if let Some(Err(err)) = fetch::fetch_missing_input(self.day, &path) {
+            eprintln!(
+                "[!] Unable to download input for Day {:02} - {err}",
+                self.day
+            );
+        }
+    }
+
+    /// Solves the selected parts of the problem, using the input file selected by
+    /// [`DaySolver::select_input_file`] and the settings in the solve context. A missing default
+    /// problem input file is downloaded first if possible. Solving stops once the cancellation
+    /// token is cancelled.
+    ///
+    /// Returns an error if the input file cannot be read or is not in the expected format.
+    pub fn solve(
+        &self,
+        input_file: Option<&str>,
+        parts: Parts,
+        context: &SolveContext,
+        token: &CancellationToken,
+    ) -> Result<DayReport, ParseError> {
+        let filename = self.select_input_file(input_file);
+        self.fetch_missing_input(input_file, &filename);
+        (self.solve)(&filename, parts, context, token)
+    }
+
+    /// Benchmarks the selected parts of the problem over the given number of runs for each phase.
+    /// The input file is selected in the same way as for [`DaySolver::solve`].
+    ///
+    /// Returns an error if the input file cannot be read or is not in the expected format.
+    pub fn bench(
+        &self,
+        input_file: Option<&str>,
+        parts: Parts,
+        context: &SolveContext,
+        iterations: usize,
+    ) -> Result<BenchReport, ParseError> {
+        let filename = self.select_input_file(input_file);
+        self.fetch_missing_input(input_file, &filename);
+        (self.bench)(&filename, parts, context, iterations)
+    }
+}
+
+/// Solves the problems for each of the solvers using the given options, printing the results for
+/// each problem to stdout in the selected output format, and any errors to stderr. Benchmark
+/// results are written to the output file if one is given in the options. Execution times (the
+/// median times when benchmarking) are saved to or compared against the baseline files given in
+/// the options.
+///
+/// Problems are solved on the number of worker threads given in the options, with the results
+/// printed in the order of the solvers.
+///
+/// Returns the reports for the problems that were solved, whether any of the problems failed or
+/// regressed, and the wall-clock and total task time of the run.
+pub fn run_solvers(solvers: &[DaySolver], options: &RunOptions) -> RunOutcome {
+    let start = Instant::now();
+    let mut reports: Vec<DayReport> = vec![];
+    let mut bench_reports: Vec<BenchReport> = vec![];
+    let mut failed = false;
+    let mut task_time = Duration::ZERO;
+    // Reports for each problem are only printed for text output
+    let text_output = options.format == OutputFormat::Text;
+    let input_file = options.input_file.as_deref();
+    let context = options.solve_context();
+    let task = |solver: &DaySolver| {
+        let task_start = Instant::now();
+        let result = match options.bench_iterations {
+            Some(iterations) => solver
+                .bench(input_file, options.parts, &context, iterations)
+                .map(|bench_report| (bench_report.report.clone(), Some(bench_report))),
+            None => {
+                // The time budget for each problem starts when solving begins
+                let token = options
+                    .timeout
+                    .map_or_else(CancellationToken::new, CancellationToken::with_timeout);
+                solver
+                    .solve(input_file, options.parts, &context, &token)
+                    .map(|report| (report, None))
+            }
+        };
+        (result, task_start.elapsed())
+    };
+    run_parallel(solvers, options.jobs, task, |solver, outcome| {
+        let (result, duration) = match outcome {
+            Ok(outcome) => outcome,
+            Err(message) => {
+                eprintln!("[!] AOC 2023 Day {} - PANIC: {message}", solver.day());
+                reports.push(DayReport::panicked(solver.day(), solver.name(), &message));
+                failed = true;
+                return;
+            }
+        };
+        task_time += duration;
+        match result {
+            Ok((report, Some(bench_report))) => {
+                if text_output {
+                    bench::print_bench_report(&bench_report);
+                }
+                bench_reports.push(bench_report);
+                reports.push(report);
+            }
+            Ok((report, None)) => {
+                if text_output {
+                    print_report(&report);
+                }
+                if let Some(phase) = report.timeout {
+                    eprintln!(
+                        "[!] AOC 2023 Day {} - TIMEOUT during {} after {:.2?}",
+                        solver.day(),
+                        phase.label(),
+                        report.total_duration()
+                    );
+                    failed = true;
+                }
+                reports.push(report);
+            }
+            Err(err) => {
+                eprintln!("[!] AOC 2023 Day {} - {err}", solver.day());
+                reports.push(DayReport::input_error(solver.day(), solver.name(), &err));
+                failed = true;
+            }
+        }
+    });
+    if let Some(filename) = &options.bench_output {
+        if let Err(err) = bench::write_bench_output(filename, &bench_reports) {
+            eprintln!("[!] Unable to write benchmark results to {filename}: {err}");
+            failed = true;
+        }
+    }
+    if let Some(filename) = &options.baseline {
+        match Baseline::load(filename) {
+            Ok(baseline) => {
+                let regressions = baseline.compare(&reports, options.threshold_percent);
+                if text_output || !regressions.is_empty() {
+                    print_regressions(&regressions, options.threshold_percent);
+                }
+                failed |= !regressions.is_empty();
+            }
+            Err(err) => {
+                eprintln!("[!] Unable to load baseline - {err}");
+                failed = true;
+            }
+        }
+    }
+    if let Some(filename) = &options.save_baseline {
+        if let Err(err) = Baseline::from_reports(&reports).save(filename) {
+            eprintln!("[!] Unable to save baseline to {filename}: {err}");
+            failed = true;
+        }
+    }
+    match options.format {
+        OutputFormat::Text => (),
+        OutputFormat::Json => print!("{}", output::format_json(&reports)),
+        OutputFormat::Csv => print!("{}", output::format_csv(&reports)),
+    }
+    RunOutcome {
+        reports,
+        failed,
+        wall_time: start.elapsed(),
+        task_time,
+    }
+}
+
+/// Runs the task for each of the items on up to the given number of worker threads. The handler
+/// is called on the current thread with the result for each item, in the order of the items, as
+/// soon as the results for all of the earlier items are available.
+///
+/// A task that panics gives the panic message as its result, so the results for the other items
+/// are still handled.
+fn run_parallel<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    task: impl Fn(&T) -> R + Sync,
+    mut handler: impl FnMut(&T, Result<R, String>),
+) {
+    let next_item = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel::<(usize, Result<R, String>)>();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next_item, task) = (&next_item, &task);
+            scope.spawn(move || {
+                // Take the next item from the shared queue until all items have been taken
+                loop {
+                    let index = next_item.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| task(item)))
+                        .map_err(|payload| panic_message(payload.as_ref()));
+                    if sender.send((index, result)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+        // Hold back results that finish out of order until the earlier results are handled
+        let mut pending: BTreeMap<usize, Result<R, String>> = BTreeMap::new();
+        let mut next_result = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&next_result) {
+                handler(&items[next_result], result);
+                next_result += 1;
+            }
+        }
+    });
+}
+
+/// Gets the message from the payload of a panic, which is a string for panics raised by `panic!`
+/// and `unwrap`.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.to_string()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+/// Processes the given input file and solves the selected parts of the problem, recording the
+/// solutions and execution times. If the cancellation token is cancelled during a phase, the
+/// problem is reported as timed out during that phase and the remaining phases are skipped.
+///
+/// Returns an error if the input file cannot be read or is not in the expected format.
+pub fn solve<S: Solution>(
+    filename: &str,
+    parts: Parts,
+    context: &SolveContext,
+    token: &CancellationToken,
+) -> Result<DayReport, ParseError> {
+    let mut report = DayReport {
+        day: S::PROBLEM_DAY,
+        name: S::PROBLEM_NAME,
+        part1: None,
+        part2: None,
+        input_duration: Duration::ZERO,
+        part1_duration: None,
+        part2_duration: None,
+        timeout: None,
+        panic: None,
+        error: None,
+        alloc_stats: vec![],
+    };
+    let start = Instant::now();
+    // Input processing
+    let (input, input_allocs) = alloc_stats::measure(|| {
+        let _span = debug_span!("parse", day = S::PROBLEM_DAY).entered();
+        S::parse_with(filename, context)
+    });
+    let input = input?;
+    report.input_duration = start.elapsed();
+    report
+        .alloc_stats
+        .extend(input_allocs.map(|stats| (Phase::Input, stats)));
+    if token.is_cancelled() {
+        report.timeout = Some(Phase::Input);
+        return Ok(report);
+    }
+    // Solve part 1
+    if parts.includes_part1() {
+        let p1_start = Instant::now();
+        let (p1_solution, p1_allocs) = alloc_stats::measure(|| {
+            let _span = debug_span!("part1", day = S::PROBLEM_DAY).entered();
+            S::part1(&input, token)
+        });
+        report.part1_duration = Some(p1_start.elapsed());
+        report
+            .alloc_stats
+            .extend(p1_allocs.map(|stats| (Phase::Part1, stats)));
+        if token.is_cancelled() {
+            report.timeout = Some(Phase::Part1);
+            return Ok(report);
+        }
+        report.part1 = Some(p1_solution);
+    }
+    // Solve part 2
+    if parts.includes_part2() {
+        let p2_start = Instant::now();
+        let (p2_solution, p2_allocs) = alloc_stats::measure(|| {
+            let _span = debug_span!("part2", day = S::PROBLEM_DAY).entered();
+            S::part2(&input, token)
+        });
+        report.part2_duration = Some(p2_start.elapsed());
+        report
+            .alloc_stats
+            .extend(p2_allocs.map(|stats| (Phase::Part2, stats)));
+        if token.is_cancelled() {
+            report.timeout = Some(Phase::Part2);
+            return Ok(report);
+        }
+        report.part2 = Some(p2_solution);
+    }
+    Ok(report)
+}
+
+/// Prints the phases that regressed against the baseline to stderr, or a confirmation to stdout if
+/// there were none.
+pub fn print_regressions(regressions: &[Regression], threshold_percent: f64) {
+    if regressions.is_empty() {
+        println!("[+] No phases regressed by more than {threshold_percent}% against the baseline");
+        return;
+    }
+    for regression in regressions {
+        eprintln!("[!] Regression - {regression}");
+    }
+    eprintln!(
+        "[!] {} phase(s) regressed by more than {threshold_percent}% against the baseline",
+        regressions.len()
+    );
+}
+
+/// Prints the solutions and execution times for a single problem to stdout.
+pub fn print_report(report: &DayReport) {
+    println!("==================================================");
+    println!("AOC 2023 Day {} - \"{}\"", report.day, report.name);
+    if let Some(p1_solution) = &report.part1 {
+        println!("[+] Part 1: {p1_solution}");
+    }
+    if let Some(p2_solution) = &report.part2 {
+        println!("[+] Part 2: {p2_solution}");
+    }
+    if let Some(phase) = report.timeout {
+        println!("[!] {}: TIMEOUT", phase.label());
+    }
+    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
+    println!("Execution times:");
+    println!("[+] Input:  {:.2?}", report.input_duration);
+    if let Some(p1_duration) = report.part1_duration {
+        println!("[+] Part 1: {p1_duration:.2?}");
+    }
+    if let Some(p2_duration) = report.part2_duration {
+        println!("[+] Part 2: {p2_duration:.2?}");
+    }
+    println!("[*] TOTAL:  {:.2?}", report.total_duration());
+    if !report.alloc_stats.is_empty() {
+        println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
+        println!("Heap allocations:");
+        for (phase, stats) in &report.alloc_stats {
+            println!("[+] {:<7} {stats}", format!("{}:", phase.label()));
+        }
+    }
+    println!("==================================================");
+}
+
+/// Prints a table summarising the solutions and execution times for all of the given problems to
+/// stdout.
+pub fn print_summary(reports: &[DayReport]) {
+    let headers = [
+        "Day", "Problem", "Part 1", "Part 2", "Input", "Part 1", "Part 2", "Total",
+    ];
+    let rows = reports
+        .iter()
+        .map(|report| {
+            vec![
+                format!("{:02}", report.day),
+                report.name.to_string(),
+                format_answer_cell(report, Phase::Part1),
+                format_answer_cell(report, Phase::Part2),
+                match (&report.panic, &report.error, report.timeout) {
+                    (Some(_), _, _) => String::from("PANIC"),
+                    (_, Some(_), _) => String::from("ERROR"),
+                    (_, _, Some(Phase::Input)) => String::from("TIMEOUT"),
+                    _ => format!("{:.2?}", report.input_duration),
+                },
+                format_optional_duration(report.part1_duration),
+                format_optional_duration(report.part2_duration),
+                format!("{:.2?}", report.total_duration()),
+            ]
+        })
+        .collect::<Vec<Vec<String>>>();
+    // Determine column widths from the widest cell in each column
+    let mut widths = headers.iter().map(|h| h.len()).collect::<Vec<usize>>();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let separator = widths
+        .iter()
+        .map(|&w| "-".repeat(w + 2))
+        .collect::<Vec<String>>()
+        .join("+");
+    println!("{}", format_table_row(&headers, &widths));
+    println!("{separator}");
+    for row in &rows {
+        println!("{}", format_table_row(row, &widths));
+    }
+    println!("{separator}");
+    let total = reports
+        .iter()
+        .map(|report| report.total_duration())
+        .sum::<Duration>();
+    println!("Total execution time: {total:.2?}");
+}
+
+/// Prints the wall-clock and total task time of the run to stdout.
+pub fn print_run_times(outcome: &RunOutcome, jobs: usize) {
+    println!(
+        "Wall-clock time: {:.2?} ({jobs} job{}), total task time: {:.2?}",
+        outcome.wall_time,
+        if jobs == 1 { "" } else { "s" },
+        outcome.task_time
+    );
+}
+
+/// Formats the answer to the given part for display in the summary table, using "PANIC" or "ERROR"
+/// if the problem panicked or its input could not be read, "TIMEOUT" if the part ran out of time and
+/// "-" for parts that were not solved.
+fn format_answer_cell(report: &DayReport, phase: Phase) -> String {
+    let answer = match phase {
+        Phase::Part1 => report.part1.as_ref(),
+        _ => report.part2.as_ref(),
+    };
+    match answer {
+        Some(answer) => answer.to_string(),
+        None if report.panic.is_some() => String::from("PANIC"),
+        None if report.error.is_some() => String::from("ERROR"),
+        None if report.timeout == Some(phase) => String::from("TIMEOUT"),
+        None => String::from("-"),
+    }
+}
+
+/// Formats the duration for display, using "-" for durations that were not recorded.
+fn format_optional_duration(duration: Option<Duration>) -> String {
+    match duration {
+        Some(duration) => format!("{duration:.2?}"),
+        None => String::from("-"),
+    }
+}
+
+/// Formats a row of the summary table, padding each cell to the width of its column.
+fn format_table_row<T: AsRef<str>>(cells: &[T], widths: &[usize]) -> String {
+    cells
+        .iter()
+        .zip(widths.iter())
+        .map(|(cell, &width)| format!(" {:<width$} ", cell.as_ref()))
+        .collect::<Vec<String>>()
+        .join("|")
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::day06::Day06;
+
+    /// Tests that results are handled in the order of the items when tasks finish out of order.
+    #[test]
+    fn test_run_parallel_order() {
+        let items = [30, 0, 20, 10];
+        let mut handled: Vec<(u64, u64)> = vec![];
+        run_parallel(
+            &items,
+            3,
+            |&millis| {
+                thread::sleep(Duration::from_millis(millis));
+                millis * 2
+            },
+            |&item, result| handled.push((item, result.unwrap())),
+        );
+        assert_eq!(vec![(30, 60), (0, 0), (20, 40), (10, 20)], handled);
+    }
+
+    /// Tests that the results after a task that panics are still handled, with the panic message
+    /// given as the result of the task that panicked.
+    #[test]
+    fn test_run_parallel_panic() {
+        let items = [0, 1, 2, 3];
+        let mut handled: Vec<Result<u64, String>> = vec![];
+        run_parallel(
+            &items,
+            2,
+            |&item| {
+                if item == 1 {
+                    panic!("day {item} not implemented");
+                }
+                item * 2
+            },
+            |_, result| handled.push(result),
+        );
+        assert_eq!(
+            vec![
+                Ok(0),
+                Err(String::from("day 1 not implemented")),
+                Ok(4),
+                Ok(6)
+            ],
+            handled
+        );
+    }
+
+    /// Tests that a problem is reported as timed out during the phase that was running when the
+    /// token was cancelled, with the remaining phases skipped.
+    #[test]
+    fn test_solve_timeout() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let context = SolveContext::default();
+        let report =
+            solve::<Day06>("./input/test/day06_01.txt", Parts::Both, &context, &token).unwrap();
+        assert_eq!(Some(Phase::Input), report.timeout);
+        assert!(report.part1.is_none() && report.part1_duration.is_none());
+        let report = solve::<Day06>(
+            "./input/test/day06_01.txt",
+            Parts::Both,
+            &context,
+            &CancellationToken::new(),
+        )
+        .unwrap();
+        assert_eq!(None, report.timeout);
+        assert_eq!(Some(Answer::Unsigned(71503)), report.part2);
+    }
+    /// Tests the status of reports for problems that were solved, timed out, panicked or had an
+    /// input error, and that only solved problems count as completed.
+    #[test]
+    fn test_report_status() {
+        let solved = DayReport::for_test(6, [Some(288), Some(71503)]);
+        let timed_out = DayReport {
+            timeout: Some(Phase::Part2),
+            ..DayReport::for_test(6, [Some(288), None])
+        };
+        let panicked = DayReport::panicked(6, "Wait For It", "oops");
+        let err = ParseError::unexpected("./input/day06.txt", 1, 1, "Time:");
+        let failed = DayReport::input_error(6, "Wait For It", &err);
+        let reports = [solved, timed_out, panicked, failed];
+        assert_eq!(
+            vec!["ok", "timeout", "panic", "error"],
+            reports.iter().map(DayReport::status).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![true, false, false, false],
+            reports.iter().map(DayReport::completed).collect::<Vec<_>>()
+        );
+        assert_eq!(Some(err.to_string()), reports[3].error);
+    }
+}
@@ -0,0 +1,213 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Template for the solver module of a new day, relative to the crate root.
+pub const TEMPLATE_FILE: &str = "template/day00.rs";
+
+/// Module declaring the solver modules for each day and the registry used by the runner, relative
+/// to the crate root.
+pub const DAYS_MODULE_FILE: &str = "src/days/mod.rs";
+
+/// Creates the files for a new day from the template under the given crate root, and registers
+/// the day with the runner. The files created are:
+///
+/// - the solver module `src/days/dayNN.rs`, generated from `template/day00.rs`
+/// - the binary `src/bin/dayNN.rs`, which runs the solver
+/// - empty input placeholders `input/dayNN.txt` and `input/test/dayNN_01.txt`
+/// - the expected answers file `input/test/dayNN_01.answers`, with no answers recorded yet
+///
+/// Returns the paths of the files created, or an error without changing any files if one of the
+/// files already exists or the day is already registered.
+pub fn new_day(root: &Path, day: u64, title: &str) -> io::Result<Vec<PathBuf>> {
+    let files = [
+        format!("src/days/day{day:02}.rs"),
+        format!("src/bin/day{day:02}.rs"),
+        format!("input/day{day:02}.txt"),
+        format!("input/test/day{day:02}_01.txt"),
+        format!("input/test/day{day:02}_01.answers"),
+    ]
+    .map(|file| root.join(file));
+    // Check for existing files before writing anything, so a day is not left half-created
+    if let Some(existing) = files.iter().find(|path| path.exists()) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", existing.display()),
+        ));
+    }
+    let template = fs::read_to_string(root.join(TEMPLATE_FILE))?;
+    let days_module = fs::read_to_string(root.join(DAYS_MODULE_FILE))?;
+    let days_module = register_day(&days_module, day).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("Day {day:02} is already registered in {DAYS_MODULE_FILE}"),
+        )
+    })?;
+    let contents = [
+        render_template(&template, day, title),
+        render_binary(day),
+        String::new(),
+        String::new(),
+        String::from("# Expected answers for the example, one \"<part> <answer>\" line per part\n"),
+    ];
+    for (path, contents) in files.iter().zip(contents) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+    }
+    fs::write(root.join(DAYS_MODULE_FILE), days_module)?;
+    Ok(files.to_vec())
+}
+
+/// Fills in the day number and problem title in the solver module template.
+pub fn render_template(template: &str, day: u64, title: &str) -> String {
+    let escaped_title = title.replace('\\', "\\\\").replace('"', "\\\"");
+    template
+        .replace(
+            "const PROBLEM_NAME: &str = \"###\";",
+            &format!("const PROBLEM_NAME: &str = \"{escaped_title}\";"),
+        )
+        .replace(
+            "const PROBLEM_DAY: u64 = 0;",
+            &format!("const PROBLEM_DAY: u64 = {day};"),
+        )
+        .replace("Day00", &format!("Day{day:02}"))
+        .replace("day00", &format!("day{day:02}"))
+        .replace("Day 00", &format!("Day {day:02}"))
+}
+
+/// Generates the binary that runs the solver for the given day.
+fn render_binary(day: u64) -> String {
+    format!(
+        "use std::process::ExitCode;
+
+use aoc2023::days::day{day:02}::Day{day:02};
+use aoc2023::run_solution;
+
+/// Processes the AOC 2023 Day {day:02} input file and solves both parts of the problem. Solutions are
+/// printed to stdout.
+pub fn main() -> ExitCode {{
+    run_solution::<Day{day:02}>()
+}}
+"
+    )
+}
+
+/// Adds the module declaration and registry entry for the given day to the contents of the days
+/// module, keeping both in order of day.
+///
+/// Returns None if the day is already declared.
+pub fn register_day(days_module: &str, day: u64) -> Option<String> {
+    let module_line = format!("pub mod day{day:02};");
+    let registry_line = format!("        DaySolver::new::<day{day:02}::Day{day:02}>(),");
+    if days_module.lines().any(|line| line.trim() == module_line) {
+        return None;
+    }
+    let mut lines = days_module
+        .lines()
+        .map(String::from)
+        .collect::<Vec<String>>();
+    insert_in_order(&mut lines, "pub mod day", module_line);
+    insert_in_order(&mut lines, "        DaySolver::new::<day", registry_line);
+    let mut output = lines.join("\n");
+    output.push('\n');
+    Some(output)
+}
+
+/// Inserts the line among the consecutive lines starting with the given prefix, before the first
+/// line that sorts after it. The day numbers are zero-padded, so sorting by text sorts by day.
+fn insert_in_order(lines: &mut Vec<String>, prefix: &str, line: String) {
+    let Some(first) = lines.iter().position(|l| l.starts_with(prefix)) else {
+        return;
+    };
+    let last = lines[first..]
+        .iter()
+        .position(|l| !l.starts_with(prefix))
+        .map_or(lines.len(), |offset| first + offset);
+    let index = lines[first..last]
+        .iter()
+        .position(|l| *l > line)
+        .map_or(last, |offset| first + offset);
+    lines.insert(index, line);
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::*;
+
+    /// Days module with Day 01 and Day 03 registered.
+    const DAYS_MODULE: &str = "use crate::runner::DaySolver;
+
+pub mod day01;
+pub mod day03;
+
+/// Returns the registry of solvers for all of the implemented problems, ordered by day.
+pub fn registry() -> Vec<DaySolver> {
+    vec![
+        DaySolver::new::<day01::Day01>(),
+        DaySolver::new::<day03::Day03>(),
+    ]
+}
+";
+
+    /// Tests that the day number and title are filled in throughout the template.
+    #[test]
+    fn test_render_template() {
+        let template =
+            fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join(TEMPLATE_FILE)).unwrap();
+        let rendered = render_template(&template, 7, "Camel \"Cards\"");
+        assert!(rendered.contains("const PROBLEM_NAME: &str = \"Camel \\\"Cards\\\"\";"));
+        assert!(rendered.contains("const PROBLEM_INPUT_FILE: &str = \"./input/day07.txt\";"));
+        assert!(rendered.contains("const PROBLEM_DAY: u64 = 7;"));
+        assert!(rendered.contains("impl Solution for Day07 {"));
+        assert!(rendered.contains("fn test_day07_part1_actual()"));
+        assert!(!rendered.contains("00"));
+        assert!(!rendered.contains("unimplemented!"));
+    }
+
+    /// Tests that the new day is registered in order of day.
+    #[test]
+    fn test_register_day() {
+        let days_module = register_day(DAYS_MODULE, 2).unwrap();
+        assert!(days_module.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(days_module.contains(
+            "        DaySolver::new::<day01::Day01>(),\n\
+             \x20       DaySolver::new::<day02::Day02>(),\n\
+             \x20       DaySolver::new::<day03::Day03>(),\n"
+        ));
+        assert!(register_day(DAYS_MODULE, 3).is_none());
+    }
+
+    /// Tests that the files for a new day are created, and are not overwritten when the day is
+    /// created again.
+    #[test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("aoc2023_{}_scaffold", std::process::id()));
+        fs::create_dir_all(root.join("template")).unwrap();
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::copy(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join(TEMPLATE_FILE),
+            root.join(TEMPLATE_FILE),
+        )
+        .unwrap();
+        fs::write(root.join(DAYS_MODULE_FILE), DAYS_MODULE).unwrap();
+        let files = new_day(&root, 7, "Camel Cards").unwrap();
+        assert_eq!(5, files.len());
+        assert!(files.iter().all(|path| path.is_file()));
+        assert!(fs::read_to_string(root.join("src/bin/day07.rs"))
+            .unwrap()
+            .contains("run_solution::<Day07>()"));
+        // Files are left unchanged if the day already exists
+        fs::write(root.join("input/day07.txt"), "input").unwrap();
+        let err = new_day(&root, 7, "Camel Cards").unwrap_err();
+        assert_eq!(io::ErrorKind::AlreadyExists, err.kind());
+        assert_eq!(
+            "input",
+            fs::read_to_string(root.join("input/day07.txt")).unwrap()
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,112 @@
+use std::env;
+use std::process::ExitCode;
+
+use crate::cancel::CancellationToken;
+use crate::cli::{self, RunOptions, RUN_OPTIONS_USAGE};
+use crate::explain;
+use crate::runner::{self, DaySolver};
+use crate::vocab::Vocabulary;
+use crate::{Answer, ParseError};
+
+/// Settings selected on the command line that change how problems are solved, passed to
+/// [`Solution::parse_with`] by the runner.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SolveContext {
+    /// Vocabulary used to read number words in Day 01 Part 2.
+    pub vocabulary: Vocabulary,
+}
+
+impl SolveContext {
+    /// Creates a new [`SolveContext`] using the given vocabulary.
+    pub fn new(vocabulary: Vocabulary) -> Self {
+        Self { vocabulary }
+    }
+}
+
+/// Common interface implemented by the solver for each AOC 2023 problem.
+///
+/// The implementing type describes the problem and provides the input parser and solver functions
+/// for both parts of the problem. The [`run_solution`] driver uses these to solve the problem and
+/// report the results.
+pub trait Solution {
+    /// Name of the problem.
+    const PROBLEM_NAME: &'static str;
+    /// Location of the problem input file.
+    const PROBLEM_INPUT_FILE: &'static str;
+    /// Day number of the problem.
+    const PROBLEM_DAY: u64;
+
+    /// Data structure produced by the input parser and consumed by the solvers.
+    type Input;
+
+    /// Processes the problem input file into the format required by the solver functions.
+    ///
+    /// Relative input file paths are resolved from the crate root, and "-" reads from stdin. Returns
+    /// an error if the input file cannot be read or is not in the expected format.
+    fn parse(filename: &str) -> Result<Self::Input, ParseError>;
+
+    /// Processes the problem input file using the settings in the solve context. Problems that
+    /// depend on the context override this method, otherwise the input file is processed by
+    /// [`Solution::parse`].
+    fn parse_with(filename: &str, _context: &SolveContext) -> Result<Self::Input, ParseError> {
+        Self::parse(filename)
+    }
+
+    /// Solves Part 1 of the problem. Long-running solvers should check the cancellation token
+    /// regularly and return early once it is cancelled.
+    fn part1(input: &Self::Input, token: &CancellationToken) -> Answer;
+
+    /// Solves Part 2 of the problem. Long-running solvers should check the cancellation token
+    /// regularly and return early once it is cancelled.
+    fn part2(input: &Self::Input, token: &CancellationToken) -> Answer;
+}
+
+/// Processes the problem input file and solves both parts of the problem. Solutions and execution
+/// times are printed to stdout, or the error is printed to stderr if the input file could not be
+/// parsed.
+///
+/// The input file can be given as the first command line argument ("-" to read from stdin),
+/// otherwise it is selected from the `AOC_INPUT_DIR` directory or the default problem input file.
+/// The other run options (such as `--bench`) are also accepted.
+pub fn run_solution<S: Solution>() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    let options = match parse_solution_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!(
+                "[!] {message}\n\nUsage: day{:02} [INPUT] [OPTIONS]\n\nOptions:\n{RUN_OPTIONS_USAGE}",
+                S::PROBLEM_DAY
+            );
+            return ExitCode::from(2);
+        }
+    };
+    if options.explain {
+        explain::install_subscriber();
+    }
+    let outcome = runner::run_solvers(&[DaySolver::new::<S>()], &options);
+    if outcome.failed {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+/// Parses the command line arguments (excluding the program name) given to the binary for a
+/// single day into the run options.
+fn parse_solution_args(args: &[String]) -> Result<RunOptions, String> {
+    let mut options = RunOptions::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if cli::parse_run_option(arg, &mut args, &mut options)? {
+            continue;
+        }
+        // Input file can be given as a positional argument
+        if options.input_file.is_none() && (arg == "-" || !arg.starts_with('-')) {
+            options.input_file = Some(arg.to_string());
+        } else {
+            return Err(format!("unexpected argument: {arg}"));
+        }
+    }
+    options.validate()?;
+    options.resolve_vocab()?;
+    Ok(options)
+}
@@ -0,0 +1,563 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+
+use crate::fetch::{self, MIN_REQUEST_INTERVAL, SESSION_ENV_VAR};
+use crate::utils::input;
+use crate::{Answer, ParseError};
+
+/// Environment variable giving the location of the guess history file to use instead of the
+/// default.
+pub const GUESSES_FILE_ENV_VAR: &str = "AOC_GUESSES_FILE";
+
+/// Default location of the guess history file, relative to the crate root.
+pub const DEFAULT_GUESSES_FILE: &str = "./guesses.txt";
+
+/// Header written at the start of a new guess history file.
+const GUESSES_FILE_HEADER: &str = "\
+# Answers submitted for the AOC 2023 problems, recorded by \"aoc2023 submit\".
+#
+# Each line is in the format \"<day> <part> <verdict> <answer>\". Keep this file out of version
+# control, as it contains the correct answers.
+";
+
+lazy_static! {
+    /// Matches the time left to wait in a rate limited response, e.g. "You have 4m 32s left to wait"
+    static ref REGEX_WAIT: Regex =
+        Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+}
+
+/// Verdict given by the server for a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GuessVerdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl GuessVerdict {
+    /// Gets the name used for the verdict in the guess history file, e.g. "too-high".
+    pub fn key(&self) -> &'static str {
+        match self {
+            GuessVerdict::Correct => "correct",
+            GuessVerdict::TooHigh => "too-high",
+            GuessVerdict::TooLow => "too-low",
+            GuessVerdict::Wrong => "wrong",
+        }
+    }
+
+    /// Gets the verdict with the given name in the guess history file.
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "correct" => Some(GuessVerdict::Correct),
+            "too-high" => Some(GuessVerdict::TooHigh),
+            "too-low" => Some(GuessVerdict::TooLow),
+            "wrong" => Some(GuessVerdict::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for GuessVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuessVerdict::Correct => write!(f, "correct"),
+            GuessVerdict::TooHigh => write!(f, "wrong (too high)"),
+            GuessVerdict::TooLow => write!(f, "wrong (too low)"),
+            GuessVerdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// Outcome of submitting an answer, parsed from the response page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    /// The answer was checked, with the given verdict.
+    Guess(GuessVerdict),
+    /// An answer was submitted too recently. The answer was not checked.
+    RateLimited { wait: Option<Duration> },
+    /// The part has already been solved, or cannot be solved yet. The answer was not checked.
+    AlreadySolved,
+    /// The response page was not recognised.
+    Unrecognised,
+}
+
+impl SubmitOutcome {
+    /// Parses the outcome of submitting an answer from the response page.
+    pub fn parse(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            SubmitOutcome::Guess(GuessVerdict::Correct)
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                SubmitOutcome::Guess(GuessVerdict::TooHigh)
+            } else if page.contains("your answer is too low") {
+                SubmitOutcome::Guess(GuessVerdict::TooLow)
+            } else {
+                SubmitOutcome::Guess(GuessVerdict::Wrong)
+            }
+        } else if page.contains("You gave an answer too recently") {
+            let wait = REGEX_WAIT.captures(page).ok().flatten().map(|captures| {
+                let minutes = captures
+                    .get(1)
+                    .and_then(|m| m.as_str().parse::<u64>().ok())
+                    .unwrap_or(0);
+                let seconds = captures[2].parse::<u64>().unwrap_or(0);
+                Duration::from_secs(minutes * 60 + seconds)
+            });
+            SubmitOutcome::RateLimited { wait }
+        } else if page.contains("You don't seem to be solving the right level") {
+            SubmitOutcome::AlreadySolved
+        } else {
+            SubmitOutcome::Unrecognised
+        }
+    }
+}
+
+/// Reason for refusing to submit an answer, based on the answers already submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    /// The part has already been solved with the given answer.
+    AlreadySolved(Answer),
+    /// The answer has already been submitted and was wrong.
+    KnownWrong(GuessVerdict),
+    /// The answer is not below an answer that was too high.
+    TooHigh(Answer),
+    /// The answer is not above an answer that was too low.
+    TooLow(Answer),
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::AlreadySolved(answer) => {
+                write!(f, "already solved with answer {answer}")
+            }
+            Rejection::KnownWrong(verdict) => {
+                write!(f, "answer was already submitted and was {verdict}")
+            }
+            Rejection::TooHigh(bound) => {
+                write!(f, "answer must be below {bound}, which was too high")
+            }
+            Rejection::TooLow(bound) => {
+                write!(f, "answer must be above {bound}, which was too low")
+            }
+        }
+    }
+}
+
+/// Error raised when an answer cannot be submitted.
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The answer was not submitted, as it cannot be correct.
+    Rejected(Rejection),
+    /// The session cookie environment variable is not set.
+    MissingSession,
+    /// The request could not be sent, or the server responded with an error status.
+    Request(String),
+    /// The guess history file could not be saved.
+    Io(io::Error),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Rejected(rejection) => write!(f, "not submitted, {rejection}"),
+            SubmitError::MissingSession => write!(
+                f,
+                "{SESSION_ENV_VAR} must be set to the session cookie to submit answers"
+            ),
+            SubmitError::Request(message) => write!(f, "request failed: {message}"),
+            SubmitError::Io(err) => write!(f, "unable to save guess history: {err}"),
+        }
+    }
+}
+
+impl Error for SubmitError {}
+
+impl From<io::Error> for SubmitError {
+    fn from(err: io::Error) -> Self {
+        SubmitError::Io(err)
+    }
+}
+
+/// Sends answers to the server, returning the response page. Implemented by [`HttpTransport`],
+/// and by stand-ins when testing.
+pub trait Transport {
+    fn post_answer(&self, day: u64, part: u8, answer: &str) -> Result<String, SubmitError>;
+}
+
+/// Transport that posts answers to the Advent of Code website (or a stand-in server) over HTTP.
+///
+/// Requests are rate limited together with the requests made by [`fetch::InputFetcher`].
+#[derive(Clone, Debug)]
+pub struct HttpTransport {
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    rate_limit_file: PathBuf,
+}
+
+impl HttpTransport {
+    /// Creates a new [`HttpTransport`] for the given server and session cookie.
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            min_interval: MIN_REQUEST_INTERVAL,
+            rate_limit_file: input::resolve_input_path(&fetch::rate_limit_file()),
+        }
+    }
+
+    /// Sets the minimum time between the start of consecutive requests.
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Sets the file recording the time of the last request.
+    pub fn with_rate_limit_file(mut self, rate_limit_file: &Path) -> Self {
+        self.rate_limit_file = rate_limit_file.to_path_buf();
+        self
+    }
+
+    /// Creates a new [`HttpTransport`] using the session cookie from the `AOC_SESSION` environment
+    /// variable, and the server from the `AOC_BASE_URL` environment variable if set.
+    pub fn from_env() -> Result<Self, SubmitError> {
+        let session = fetch::session_from_env().ok_or(SubmitError::MissingSession)?;
+        Ok(Self::new(&fetch::base_url_from_env(), &session))
+    }
+}
+
+impl Transport for HttpTransport {
+    fn post_answer(&self, day: u64, part: u8, answer: &str) -> Result<String, SubmitError> {
+        fetch::wait_for_rate_limit(self.min_interval, &self.rate_limit_file);
+        let url = format!("{}/2023/day/{day}/answer", self.base_url);
+        let response = fetch::http_agent()
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|err| SubmitError::Request(err.to_string())),
+            Err(ureq::Error::Status(status @ (400 | 401 | 403), _)) => Err(SubmitError::Request(
+                format!("HTTP {status}, check that {SESSION_ENV_VAR} is valid"),
+            )),
+            Err(ureq::Error::Status(status, _)) => {
+                Err(SubmitError::Request(format!("HTTP {status}")))
+            }
+            Err(ureq::Error::Transport(err)) => Err(SubmitError::Request(err.to_string())),
+        }
+    }
+}
+
+/// Answer submitted for one part of a problem, and the verdict given by the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Guess {
+    pub day: u64,
+    pub part: u8,
+    pub verdict: GuessVerdict,
+    pub answer: Answer,
+}
+
+/// History of the answers submitted for each problem, used to avoid submitting answers that
+/// cannot be correct.
+#[derive(Clone, Debug, Default)]
+pub struct GuessHistory {
+    filename: String,
+    guesses: Vec<Guess>,
+}
+
+impl GuessHistory {
+    /// Loads the guess history from the file named by the `AOC_GUESSES_FILE` environment variable,
+    /// or the default guess history file if the variable is not set. The history is empty if the
+    /// file does not exist yet.
+    pub fn load() -> Result<Self, ParseError> {
+        let filename = env::var(GUESSES_FILE_ENV_VAR)
+            .ok()
+            .filter(|filename| !filename.is_empty())
+            .unwrap_or_else(|| DEFAULT_GUESSES_FILE.to_string());
+        Self::load_from(&filename)
+    }
+
+    /// Loads the guess history from the given file. Relative paths are resolved from the crate
+    /// root.
+    pub fn load_from(filename: &str) -> Result<Self, ParseError> {
+        if !input::resolve_input_path(filename).exists() {
+            return Ok(Self {
+                filename: filename.to_string(),
+                guesses: vec![],
+            });
+        }
+        let raw_input = input::read_input(filename)?;
+        Self::parse(filename, &raw_input)
+    }
+
+    /// Parses the contents of a guess history file.
+    fn parse(filename: &str, raw_input: &str) -> Result<Self, ParseError> {
+        let mut guesses = vec![];
+        for (i, line) in raw_input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line.splitn(4, ' ').collect::<Vec<&str>>();
+            let day = fields.first().and_then(|s| s.parse::<u64>().ok());
+            let part = fields.get(1).and_then(|s| s.parse::<u8>().ok());
+            let verdict = fields.get(2).and_then(|s| GuessVerdict::from_key(s));
+            match (day, part, verdict, fields.get(3)) {
+                (Some(day), Some(part), Some(verdict), Some(answer)) => guesses.push(Guess {
+                    day,
+                    part,
+                    verdict,
+                    answer: Answer::parse(answer),
+                }),
+                _ => {
+                    return Err(ParseError::unexpected(
+                        filename,
+                        i + 1,
+                        1,
+                        "guess in format \"<day> <part> <verdict> <answer>\"",
+                    ))
+                }
+            }
+        }
+        Ok(Self {
+            filename: filename.to_string(),
+            guesses,
+        })
+    }
+
+    /// Saves the guess history to the file it was loaded from.
+    pub fn save(&self) -> io::Result<()> {
+        let mut output = String::from(GUESSES_FILE_HEADER);
+        for guess in &self.guesses {
+            output.push_str(&format!(
+                "{} {} {} {}\n",
+                guess.day,
+                guess.part,
+                guess.verdict.key(),
+                guess.answer
+            ));
+        }
+        fs::write(input::resolve_input_path(&self.filename), output)
+    }
+
+    /// Gets the answers submitted for the given day and part, in the order they were submitted.
+    pub fn guesses(&self, day: u64, part: u8) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |guess| guess.day == day && guess.part == part)
+    }
+
+    /// Records the verdict for an answer submitted for the given day and part.
+    pub fn record(&mut self, day: u64, part: u8, answer: &Answer, verdict: GuessVerdict) {
+        self.guesses.push(Guess {
+            day,
+            part,
+            verdict,
+            answer: answer.clone(),
+        });
+    }
+
+    /// Checks the answer for the given day and part against the answers already submitted,
+    /// returning the reason the answer cannot be correct (if any).
+    ///
+    /// Answers are rejected if the part has already been solved, if the same answer was wrong, or
+    /// if the answer is outside of the bounds given by answers that were too high or too low.
+    pub fn check(&self, day: u64, part: u8, answer: &Answer) -> Result<(), Rejection> {
+        let mut upper: Option<&Answer> = None;
+        let mut lower: Option<&Answer> = None;
+        for guess in self.guesses(day, part) {
+            match guess.verdict {
+                GuessVerdict::Correct => {
+                    return Err(Rejection::AlreadySolved(guess.answer.clone()))
+                }
+                _ if guess.answer == *answer => return Err(Rejection::KnownWrong(guess.verdict)),
+                GuessVerdict::TooHigh if upper.is_none_or(|upper| guess.answer < *upper) => {
+                    upper = Some(&guess.answer)
+                }
+                GuessVerdict::TooLow if lower.is_none_or(|lower| guess.answer > *lower) => {
+                    lower = Some(&guess.answer)
+                }
+                _ => (),
+            }
+        }
+        if let Some(upper) = upper.filter(|upper| *answer >= **upper) {
+            return Err(Rejection::TooHigh(upper.clone()));
+        }
+        if let Some(lower) = lower.filter(|lower| *answer <= **lower) {
+            return Err(Rejection::TooLow(lower.clone()));
+        }
+        Ok(())
+    }
+}
+
+/// Submits the answer for the given day and part using the transport, unless the guess history
+/// shows that the answer cannot be correct. The verdict for the answer is recorded in the guess
+/// history, which is then saved.
+pub fn submit(
+    transport: &dyn Transport,
+    history: &mut GuessHistory,
+    day: u64,
+    part: u8,
+    answer: &Answer,
+) -> Result<SubmitOutcome, SubmitError> {
+    history
+        .check(day, part, answer)
+        .map_err(SubmitError::Rejected)?;
+    let page = transport.post_answer(day, part, &answer.to_string())?;
+    let outcome = SubmitOutcome::parse(&page);
+    if let SubmitOutcome::Guess(verdict) = outcome {
+        history.record(day, part, answer, verdict);
+        history.save()?;
+    }
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::utils::stub_server;
+
+    /// Stand-in transport that answers each submission with the next page given, recording the
+    /// answers submitted.
+    struct StubTransport {
+        pages: RefCell<Vec<&'static str>>,
+        submitted: RefCell<Vec<String>>,
+    }
+
+    impl Transport for StubTransport {
+        fn post_answer(&self, _day: u64, _part: u8, answer: &str) -> Result<String, SubmitError> {
+            self.submitted.borrow_mut().push(answer.to_string());
+            Ok(self.pages.borrow_mut().remove(0).to_string())
+        }
+    }
+
+    /// Returns an empty guess history saved to a uniquely named file in the temporary directory.
+    fn temp_history(name: &str) -> GuessHistory {
+        let path = env::temp_dir().join(format!("aoc2023_{}_{name}", std::process::id()));
+        GuessHistory::load_from(&path.to_string_lossy()).unwrap()
+    }
+
+    /// Tests that the outcome of submitting an answer is parsed from the response page.
+    #[test]
+    fn test_submit_outcome_parse() {
+        let parse = |page: &str| SubmitOutcome::parse(&format!("<article><p>{page}</p></article>"));
+        assert_eq!(
+            SubmitOutcome::Guess(GuessVerdict::Correct),
+            parse("That's the right answer! You are one gold star closer.")
+        );
+        assert_eq!(
+            SubmitOutcome::Guess(GuessVerdict::TooHigh),
+            parse("That's not the right answer; your answer is too high.")
+        );
+        assert_eq!(
+            SubmitOutcome::Guess(GuessVerdict::TooLow),
+            parse("That's not the right answer; your answer is too low.")
+        );
+        assert_eq!(
+            SubmitOutcome::Guess(GuessVerdict::Wrong),
+            parse("That's not the right answer. If you're stuck, ...")
+        );
+        assert_eq!(
+            SubmitOutcome::RateLimited {
+                wait: Some(Duration::from_secs(272))
+            },
+            parse("You gave an answer too recently. You have 4m 32s left to wait.")
+        );
+        assert_eq!(
+            SubmitOutcome::AlreadySolved,
+            parse("You don't seem to be solving the right level.")
+        );
+        assert_eq!(SubmitOutcome::Unrecognised, parse("Hello"));
+    }
+
+    /// Tests that answers that cannot be correct are rejected using the guess history.
+    #[test]
+    fn test_guess_history_check() {
+        let raw_input = "# comment\n7 1 too-high 500\n7 1 too-low 100\n7 1 too-high 400\n\
+                         7 1 wrong 250\n7 2 correct ABC DEF\n";
+        let history = GuessHistory::parse("guesses.txt", raw_input).unwrap();
+        let check = |part, answer: Answer| history.check(7, part, &answer);
+        assert_eq!(Ok(()), check(1, 399.into()));
+        assert_eq!(Ok(()), check(1, 101.into()));
+        assert_eq!(Err(Rejection::TooHigh(400.into())), check(1, 450.into()));
+        assert_eq!(Err(Rejection::TooLow(100.into())), check(1, 99.into()));
+        assert_eq!(
+            Err(Rejection::KnownWrong(GuessVerdict::TooLow)),
+            check(1, 100.into())
+        );
+        assert_eq!(
+            Err(Rejection::KnownWrong(GuessVerdict::Wrong)),
+            check(1, 250.into())
+        );
+        assert_eq!(
+            Err(Rejection::AlreadySolved("ABC DEF".into())),
+            check(2, "XYZ".into())
+        );
+        assert_eq!(Ok(()), history.check(8, 1, &999.into()));
+    }
+
+    /// Tests that verdicts are recorded and saved, and known wrong answers are not resubmitted.
+    #[test]
+    fn test_submit_records_guesses() {
+        let transport = StubTransport {
+            pages: RefCell::new(vec![
+                "That's not the right answer; your answer is too low.",
+                "That's the right answer!",
+            ]),
+            submitted: RefCell::new(vec![]),
+        };
+        let mut history = temp_history("guesses_submit.txt");
+        let outcome = submit(&transport, &mut history, 7, 1, &10.into()).unwrap();
+        assert_eq!(SubmitOutcome::Guess(GuessVerdict::TooLow), outcome);
+        let err = submit(&transport, &mut history, 7, 1, &10.into()).unwrap_err();
+        assert_eq!(
+            "not submitted, answer was already submitted and was wrong (too low)",
+            err.to_string()
+        );
+        let outcome = submit(&transport, &mut history, 7, 1, &12.into()).unwrap();
+        assert_eq!(SubmitOutcome::Guess(GuessVerdict::Correct), outcome);
+        assert_eq!(vec!["10", "12"], *transport.submitted.borrow());
+        let saved = GuessHistory::load_from(&history.filename).unwrap();
+        assert_eq!(2, saved.guesses(7, 1).count());
+        fs::remove_file(input::resolve_input_path(&history.filename)).unwrap();
+    }
+
+    /// Tests that the HTTP transport posts the answer form with the session cookie.
+    #[test]
+    fn test_http_transport_post_answer() {
+        let (base_url, requests) =
+            stub_server::start(|_| (200, String::from("<p>That's the right answer!</p>")));
+        let rate_limit_file = env::temp_dir().join(format!(
+            "aoc2023_{}_submit_last_request",
+            std::process::id()
+        ));
+        let transport = HttpTransport::new(&base_url, "abc123")
+            .with_min_interval(Duration::ZERO)
+            .with_rate_limit_file(&rate_limit_file);
+        let page = transport.post_answer(7, 2, "ABC DEF").unwrap();
+        fs::remove_file(&rate_limit_file).unwrap();
+        assert_eq!(
+            SubmitOutcome::Guess(GuessVerdict::Correct),
+            SubmitOutcome::parse(&page)
+        );
+        let requests = requests.lock().unwrap();
+        assert!(requests[0]
+            .head
+            .starts_with("POST /2023/day/7/answer HTTP/1.1\r\n"));
+        assert!(requests[0].head.contains("session=abc123"));
+        assert_eq!("level=2&answer=ABC+DEF", requests[0].body);
+    }
+}
@@ -0,0 +1,52 @@
+/// Point on a two-dimensional grid, with the y-coordinate increasing downwards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Point2D {
+    x: i64,
+    y: i64,
+}
+
+impl Point2D {
+    /// Creates a new point with the given coordinates.
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Gets the x-coordinate of the point.
+    pub fn x(&self) -> i64 {
+        self.x
+    }
+
+    /// Gets the y-coordinate of the point.
+    pub fn y(&self) -> i64 {
+        self.y
+    }
+
+    /// Gets the eight points surrounding the point, including the diagonal neighbours. Points are
+    /// given row by row, from the top left to the bottom right.
+    pub fn get_surrounding_points(&self) -> Vec<Point2D> {
+        let mut points: Vec<Point2D> = vec![];
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if dx != 0 || dy != 0 {
+                    points.push(Point2D::new(self.x + dx, self.y + dy));
+                }
+            }
+        }
+        points
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the surrounding points include the diagonal neighbours but not the point itself.
+    #[test]
+    fn test_get_surrounding_points() {
+        let points = Point2D::new(3, 5).get_surrounding_points();
+        assert_eq!(8, points.len());
+        assert_eq!(Point2D::new(2, 4), points[0]);
+        assert_eq!(Point2D::new(4, 6), points[7]);
+        assert!(!points.contains(&Point2D::new(3, 5)));
+    }
+}
@@ -0,0 +1,234 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+use crate::utils::{hex, input};
+
+/// Environment variable giving the hex-encoded key used to encrypt and decrypt input files.
+pub const INPUT_KEY_ENV_VAR: &str = "AOC_INPUT_KEY";
+
+/// Environment variable giving the location of the key file to use instead of the default.
+pub const INPUT_KEY_FILE_ENV_VAR: &str = "AOC_INPUT_KEY_FILE";
+
+/// Default location of the key file, relative to the crate root.
+pub const DEFAULT_INPUT_KEY_FILE: &str = "./.input_key";
+
+/// File extension added to the name of an input file when it is encrypted.
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+/// Marker at the start of every encrypted input file, followed by the nonce and the ciphertext.
+const ENCRYPTED_HEADER: &[u8] = b"AOC2023-ENC1\n";
+
+/// Length of the nonce in bytes, stored after the header of each encrypted input file.
+const NONCE_LEN: usize = 12;
+
+/// Key used to encrypt and decrypt the problem input files with ChaCha20-Poly1305.
+///
+/// The key is given as 64 hex digits, either by the `AOC_INPUT_KEY` environment variable or in a
+/// key file. The key itself is never printed by the [`fmt::Debug`] implementation.
+#[derive(Clone)]
+pub struct InputKey([u8; 32]);
+
+impl fmt::Debug for InputKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "InputKey(..)")
+    }
+}
+
+impl InputKey {
+    /// Generates a new random key.
+    pub fn generate() -> Self {
+        Self(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    /// Parses the key from its hex encoding, ignoring surrounding whitespace.
+    pub fn from_hex(s: &str) -> Option<Self> {
+        let key = hex::decode(s.trim())?;
+        key.try_into().ok().map(Self)
+    }
+
+    /// Encodes the key as 64 lowercase hex digits.
+    pub fn to_hex(&self) -> String {
+        hex::encode(&self.0)
+    }
+
+    /// Loads the key from the `AOC_INPUT_KEY` environment variable if set. Otherwise, the key is
+    /// read from the key file returned by [`key_file`].
+    ///
+    /// Returns an error if no key is available or the key is not 64 hex digits.
+    pub fn load() -> io::Result<Self> {
+        let (source, hex) = match env::var(INPUT_KEY_ENV_VAR) {
+            Ok(hex) if !hex.is_empty() => (INPUT_KEY_ENV_VAR.to_string(), hex),
+            _ => {
+                let filename = key_file();
+                let path = input::resolve_input_path(&filename);
+                let hex = fs::read_to_string(path).map_err(|err| {
+                    let message = format!(
+                        "unable to read input key file {filename} ({err}), \
+                         set {INPUT_KEY_ENV_VAR} or run \"aoc2023 inputs keygen\""
+                    );
+                    io::Error::new(err.kind(), message)
+                })?;
+                (filename, hex)
+            }
+        };
+        Self::from_hex(&hex).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("input key from {source} is not 64 hex digits"),
+            )
+        })
+    }
+
+    /// Encrypts the contents of an input file, returning the header, nonce and ciphertext.
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&self.0));
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext)
+            .expect("input file too large to encrypt");
+        [ENCRYPTED_HEADER, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    /// Decrypts the contents of an encrypted input file.
+    ///
+    /// Returns an error if the contents are not an encrypted input file, or cannot be decrypted
+    /// with this key (including if the contents have been modified).
+    pub fn decrypt(&self, data: &[u8]) -> io::Result<Vec<u8>> {
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message);
+        let Some(data) = data.strip_prefix(ENCRYPTED_HEADER) else {
+            return Err(invalid("not an encrypted input file"));
+        };
+        if data.len() < NONCE_LEN {
+            return Err(invalid("encrypted input file is truncated"));
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&self.0));
+        cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| invalid("unable to decrypt input file, wrong key or modified file"))
+    }
+}
+
+/// Returns the name of the key file given by the `AOC_INPUT_KEY_FILE` environment variable, or the
+/// default key file if the variable is not set.
+pub fn key_file() -> String {
+    env::var(INPUT_KEY_FILE_ENV_VAR)
+        .ok()
+        .filter(|filename| !filename.is_empty())
+        .unwrap_or_else(|| DEFAULT_INPUT_KEY_FILE.to_string())
+}
+
+/// Returns the path of the encrypted copy of the given input file, e.g. "day01.txt.enc".
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(format!(".{ENCRYPTED_EXTENSION}"));
+    PathBuf::from(name)
+}
+
+/// Reads and decrypts the encrypted input file, using the key given by [`InputKey::load`].
+pub fn read_encrypted(path: &Path) -> io::Result<String> {
+    let key = InputKey::load()?;
+    let plaintext = key.decrypt(&fs::read(path)?)?;
+    String::from_utf8(plaintext).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Encrypts each ".txt" file directly within the directory, writing the encrypted copy alongside
+/// it. Existing encrypted copies are replaced.
+///
+/// Returns the pairs of plaintext and encrypted files, ordered by file name.
+pub fn encrypt_dir(dir: &Path, key: &InputKey) -> io::Result<Vec<(PathBuf, PathBuf)>> {
+    let mut files = vec![];
+    for path in list_files(dir, "txt")? {
+        let encrypted = encrypted_path(&path);
+        fs::write(&encrypted, key.encrypt(&fs::read(&path)?))?;
+        files.push((path, encrypted));
+    }
+    Ok(files)
+}
+
+/// Decrypts each ".txt.enc" file directly within the directory, writing the plaintext file
+/// alongside it. Existing plaintext files are replaced.
+///
+/// Returns the pairs of encrypted and plaintext files, ordered by file name.
+pub fn decrypt_dir(dir: &Path, key: &InputKey) -> io::Result<Vec<(PathBuf, PathBuf)>> {
+    let mut files = vec![];
+    for path in list_files(dir, ENCRYPTED_EXTENSION)? {
+        if path
+            .file_stem()
+            .and_then(|stem| Path::new(stem).extension())
+            != Some("txt".as_ref())
+        {
+            continue;
+        }
+        let plaintext = key
+            .decrypt(&fs::read(&path)?)
+            .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())))?;
+        let decrypted = path.with_extension("");
+        fs::write(&decrypted, plaintext)?;
+        files.push((path, decrypted));
+    }
+    Ok(files)
+}
+
+/// Lists the files directly within the directory that have the given extension, ordered by name.
+fn list_files(dir: &Path, extension: &str) -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension() == Some(extension.as_ref()) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that encrypted input files are decrypted back to the original contents.
+    #[test]
+    fn test_encrypt_decrypt_roundtrip() {
+        let key = InputKey::generate();
+        let encrypted = key.encrypt(b"Time:      7  15   30\n");
+        assert!(encrypted.starts_with(ENCRYPTED_HEADER));
+        assert_ne!(
+            encrypted,
+            key.encrypt(b"Time:      7  15   30\n"),
+            "nonce should differ"
+        );
+        assert_eq!(
+            b"Time:      7  15   30\n".to_vec(),
+            key.decrypt(&encrypted).unwrap()
+        );
+    }
+
+    /// Tests that decryption fails for the wrong key, modified contents or unencrypted files.
+    #[test]
+    fn test_decrypt_invalid() {
+        let key = InputKey::generate();
+        let mut encrypted = key.encrypt(b"3four4\n");
+        assert!(InputKey::generate().decrypt(&encrypted).is_err());
+        let last = encrypted.len() - 1;
+        encrypted[last] ^= 1;
+        assert!(key.decrypt(&encrypted).is_err());
+        assert!(key.decrypt(b"3four4\n").is_err());
+    }
+
+    /// Tests that keys are parsed from and encoded as 64 hex digits.
+    #[test]
+    fn test_input_key_hex() {
+        let hex = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+        let key = InputKey::from_hex(&format!("{hex}\n")).unwrap();
+        assert_eq!(hex, key.to_hex());
+        assert!(InputKey::from_hex(&hex[..62]).is_none());
+        assert!(InputKey::from_hex(&hex.replace('a', "g")).is_none());
+    }
+}
@@ -0,0 +1,32 @@
+/// Encodes the bytes as lowercase hex digits.
+pub fn encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Decodes the string of hex digits into bytes. Returns None if the string has an odd length or
+/// contains any character other than a hex digit, such as the leading `+` that
+/// [`u8::from_str_radix`] would accept.
+pub fn decode(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 == 1 || !s.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that bytes are encoded as hex digits and decoded back again.
+    #[test]
+    fn test_hex_roundtrip() {
+        assert_eq!("00ff1a", encode(&[0x00, 0xff, 0x1a]));
+        assert_eq!(Some(vec![0x00, 0xff, 0x1a]), decode("00FF1a"));
+        assert_eq!(None, decode("0ff"));
+        assert_eq!(None, decode("0g"));
+        assert_eq!(None, decode("+f"));
+    }
+}
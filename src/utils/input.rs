@@ -0,0 +1,142 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::utils::encryption;
+use crate::ParseError;
+
+/// Environment variable giving the directory from which the default problem input files are read.
+pub const INPUT_DIR_ENV_VAR: &str = "AOC_INPUT_DIR";
+
+/// Input file name used to read the problem input from stdin.
+pub const STDIN_INPUT_FILE: &str = "-";
+
+/// Selects the input file for the given day.
+///
+/// The input file given as an argument takes priority. Otherwise, the input file for the day is
+/// taken from the directory named by the `AOC_INPUT_DIR` environment variable (if set), falling
+/// back to the default problem input file.
+pub fn select_input_file(day: u64, default_file: &str, arg: Option<&str>) -> String {
+    select_input_file_from(day, default_file, arg, env::var(INPUT_DIR_ENV_VAR).ok())
+}
+
+/// Selects the input file for the given day using the input directory (if any) provided.
+fn select_input_file_from(
+    day: u64,
+    default_file: &str,
+    arg: Option<&str>,
+    input_dir: Option<String>,
+) -> String {
+    if let Some(filename) = arg {
+        return filename.to_string();
+    }
+    match input_dir.filter(|dir| !dir.is_empty()) {
+        Some(dir) => Path::new(&dir)
+            .join(format!("day{day:02}.txt"))
+            .to_string_lossy()
+            .to_string(),
+        None => default_file.to_string(),
+    }
+}
+
+/// Resolves the path of the input file. Relative paths are resolved from the crate root rather
+/// than the current working directory.
+pub fn resolve_input_path(filename: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(filename)
+}
+
+/// Reads the contents of the input file, or reads from stdin if the file name is "-".
+pub fn read_input(filename: &str) -> Result<String, ParseError> {
+    let raw_input = if filename == STDIN_INPUT_FILE {
+        let mut raw_input = String::new();
+        io::stdin()
+            .read_to_string(&mut raw_input)
+            .map(|_| raw_input)
+    } else {
+        read_input_file(&resolve_input_path(filename))
+    };
+    raw_input.map_err(|err| ParseError::io(filename, err))
+}
+
+/// Reads the contents of the input file. If the file does not exist but an encrypted copy of it
+/// does (e.g. "day01.txt.enc"), the encrypted copy is decrypted instead.
+fn read_input_file(path: &Path) -> io::Result<String> {
+    if !path.exists() {
+        let encrypted = encryption::encrypted_path(path);
+        if encrypted.exists() {
+            return encryption::read_encrypted(&encrypted);
+        }
+    }
+    fs::read_to_string(path)
+}
+
+/// Writes the given contents to a uniquely named input file in the temporary directory, returning
+/// the path of the file. Used by tests that check the handling of malformed input files.
+#[cfg(test)]
+pub(crate) fn write_temp_input(name: &str, contents: &str) -> String {
+    let path = env::temp_dir().join(format!("aoc2023_{}_{name}", std::process::id()));
+    fs::write(&path, contents).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+/// Checks if the problem input file can be read, for tests that need it. Problem inputs may be left
+/// out of a checkout, or only an encrypted copy may be present without its key, so tests that need
+/// the input are skipped rather than failing when it cannot be read.
+#[cfg(test)]
+pub(crate) fn test_input_available(filename: &str) -> bool {
+    match read_input(filename) {
+        Ok(_) => true,
+        Err(err) => {
+            eprintln!("[*] Skipping test, problem input is not available - {err}");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the input file given as an argument takes priority over other sources.
+    #[test]
+    fn test_select_input_file_arg() {
+        let filename = select_input_file_from(
+            5,
+            "./input/day05.txt",
+            Some("./other/day05.txt"),
+            Some(String::from("/tmp/inputs")),
+        );
+        assert_eq!("./other/day05.txt", filename);
+    }
+
+    /// Tests that the input directory is used when no input file argument is given.
+    #[test]
+    fn test_select_input_file_input_dir() {
+        let filename =
+            select_input_file_from(5, "./input/day05.txt", None, Some(String::from("/tmp/in")));
+        assert_eq!("/tmp/in/day05.txt", filename);
+    }
+
+    /// Tests that the default input file is used when no other source is given.
+    #[test]
+    fn test_select_input_file_default() {
+        let filename = select_input_file_from(5, "./input/day05.txt", None, None);
+        assert_eq!("./input/day05.txt", filename);
+    }
+
+    /// Tests that relative input paths are resolved from the crate root.
+    #[test]
+    fn test_resolve_input_path_relative() {
+        let path = resolve_input_path("./input/test/day05_01.txt");
+        assert!(path.starts_with(env!("CARGO_MANIFEST_DIR")));
+        assert!(path.is_file());
+    }
+
+    /// Tests that a missing input file is reported as an error.
+    #[test]
+    fn test_read_input_missing_file() {
+        let err = read_input("./input/day00_missing.txt").unwrap_err();
+        assert_eq!("./input/day00_missing.txt", err.file());
+    }
+}
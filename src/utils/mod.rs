@@ -1 +1,7 @@
-
+pub mod cartography;
+pub mod encryption;
+pub mod hex;
+pub mod input;
+pub mod scanner;
+#[cfg(test)]
+pub(crate) mod stub_server;
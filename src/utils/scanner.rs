@@ -0,0 +1,112 @@
+use std::cmp::Reverse;
+
+use aho_corasick::{AhoCorasick, BuildError, MatchKind};
+
+/// Token found in a string by a [`TokenScanner`], with the value the token maps to.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TokenMatch<'a, T> {
+    /// Byte offset of the start of the token.
+    pub start: usize,
+    /// Byte offset one past the end of the token.
+    pub end: usize,
+    pub value: &'a T,
+}
+
+// Implemented by hand, as the derived implementations would require T to be Copy
+impl<T> Clone for TokenMatch<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TokenMatch<'_, T> {}
+
+impl<T> TokenMatch<'_, T> {
+    /// Gets the matched token from the string that was scanned.
+    pub fn token<'s>(&self, s: &'s str) -> &'s str {
+        &s[self.start..self.end]
+    }
+}
+
+/// Multi-pattern scanner that finds the tokens of a vocabulary in a string, built on an
+/// Aho-Corasick automaton so every token is searched for in a single pass.
+///
+/// Overlapping tokens are all found, e.g. both "eight" and "two" in "eightwo".
+#[derive(Clone, Debug)]
+pub struct TokenScanner<T> {
+    automaton: AhoCorasick,
+    values: Vec<T>,
+}
+
+impl<T> TokenScanner<T> {
+    /// Creates a new [`TokenScanner`] from the vocabulary of tokens and the values they map to.
+    ///
+    /// Returns an error if the automaton for the vocabulary is too large to build.
+    pub fn new<'t>(vocabulary: impl IntoIterator<Item = (&'t str, T)>) -> Result<Self, BuildError> {
+        let (tokens, values): (Vec<&str>, Vec<T>) = vocabulary.into_iter().unzip();
+        // Standard match semantics are required to report overlapping matches
+        let automaton = AhoCorasick::builder()
+            .match_kind(MatchKind::Standard)
+            .build(tokens)?;
+        Ok(Self { automaton, values })
+    }
+
+    /// Finds the first and last tokens in the string, ordered by their starting position. Where
+    /// tokens start at the same position, the longest token is used.
+    ///
+    /// Returns None if the string does not contain any tokens.
+    pub fn first_and_last(&self, s: &str) -> Option<(TokenMatch<'_, T>, TokenMatch<'_, T>)> {
+        let mut first: Option<TokenMatch<T>> = None;
+        let mut last: Option<TokenMatch<T>> = None;
+        for found in self.automaton.find_overlapping_iter(s) {
+            let token = TokenMatch {
+                start: found.start(),
+                end: found.end(),
+                value: &self.values[found.pattern().as_usize()],
+            };
+            let key = (token.start, Reverse(token.end));
+            if first.is_none_or(|first| key < (first.start, Reverse(first.end))) {
+                first = Some(token);
+            }
+            if last.is_none_or(|last| (token.start, token.end) > (last.start, last.end)) {
+                last = Some(token);
+            }
+        }
+        Some((first?, last?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the first and last tokens are found when tokens overlap.
+    #[test]
+    fn test_first_and_last_overlapping() {
+        let scanner = TokenScanner::new([("one", 1), ("two", 2), ("eight", 8), ("2", 2)]).unwrap();
+        let (first, last) = scanner.first_and_last("eightwo").unwrap();
+        assert_eq!((0, 5, &8), (first.start, first.end, first.value));
+        assert_eq!((4, 7, &2), (last.start, last.end, last.value));
+        assert_eq!("two", last.token("eightwo"));
+        let (first, last) = scanner.first_and_last("xtwone2").unwrap();
+        assert_eq!(
+            ("two", "2"),
+            (first.token("xtwone2"), last.token("xtwone2"))
+        );
+        let (first, last) = scanner.first_and_last("aoneb").unwrap();
+        assert_eq!(first, last);
+        assert_eq!(
+            None,
+            scanner.first_and_last("abc").map(|(first, _)| first.start)
+        );
+    }
+
+    /// Tests that the longest token is used where tokens start at the same position.
+    #[test]
+    fn test_first_and_last_longest() {
+        let scanner = TokenScanner::new([("1", "1"), ("12", "12"), ("2", "2")]).unwrap();
+        let (first, last) = scanner.first_and_last("x12").unwrap();
+        assert_eq!(&"12", first.value);
+        assert_eq!(&"2", last.value);
+    }
+}
@@ -0,0 +1,70 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Request received by a stub server.
+#[derive(Clone, Debug)]
+pub(crate) struct StubRequest {
+    /// Request line and headers, each ending with "\r\n".
+    pub(crate) head: String,
+    pub(crate) body: String,
+}
+
+impl StubRequest {
+    /// Gets the path from the request line, e.g. "/2023/day/3/input".
+    pub(crate) fn path(&self) -> &str {
+        self.head.split(' ').nth(1).unwrap_or_default()
+    }
+}
+
+/// Requests received by a stub server, in the order they were received.
+pub(crate) type StubRequests = Arc<Mutex<Vec<StubRequest>>>;
+
+/// Starts a stub HTTP server on a local port, used to test HTTP clients without network access.
+/// Each request is answered with the status code and body returned by the handler, and the
+/// connection is closed after each response.
+///
+/// Returns the base URL of the server (e.g. "http://127.0.0.1:4321") and the requests received.
+pub(crate) fn start(
+    handler: impl Fn(&StubRequest) -> (u16, String) + Send + 'static,
+) -> (String, StubRequests) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests: StubRequests = Arc::new(Mutex::new(vec![]));
+    let received = Arc::clone(&requests);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut head = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse::<usize>().unwrap_or_default();
+                    }
+                }
+                head.push_str(&line);
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            let request = StubRequest {
+                head,
+                body: String::from_utf8_lossy(&body).to_string(),
+            };
+            let (status, body) = handler(&request);
+            received.lock().unwrap().push(request);
+            let response = format!(
+                "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+    (base_url, requests)
+}
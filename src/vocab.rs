@@ -0,0 +1,245 @@
+use std::env;
+
+use crate::utils::input;
+use crate::utils::scanner::TokenScanner;
+use crate::ParseError;
+
+/// Environment variable giving the vocabulary used to read number words in Day 01 Part 2, either
+/// the name of a built-in vocabulary or a vocabulary file.
+pub const VOCAB_ENV_VAR: &str = "AOC_VOCAB";
+
+/// Name of the vocabulary used when none is selected.
+pub const DEFAULT_VOCAB: &str = "english";
+
+/// Number words from "one" to "nine", as used by the puzzle.
+const ENGLISH_WORDS: [(&str, &str); 9] = [
+    ("one", "1"),
+    ("two", "2"),
+    ("three", "3"),
+    ("four", "4"),
+    ("five", "5"),
+    ("six", "6"),
+    ("seven", "7"),
+    ("eight", "8"),
+    ("nine", "9"),
+];
+
+/// Number words added to [`ENGLISH_WORDS`] by the "english-extended" vocabulary.
+const ENGLISH_EXTENDED_WORDS: [(&str, &str); 12] = [
+    ("zero", "0"),
+    ("ten", "10"),
+    ("eleven", "11"),
+    ("twelve", "12"),
+    ("thirteen", "13"),
+    ("fourteen", "14"),
+    ("fifteen", "15"),
+    ("sixteen", "16"),
+    ("seventeen", "17"),
+    ("eighteen", "18"),
+    ("nineteen", "19"),
+    ("twenty", "20"),
+];
+
+/// Vocabulary of tokens that represent digits in a calibration document, mapping each token to
+/// the digits it contributes. Multi-digit tokens such as "twelve" contribute all of their digits,
+/// so the line "twelve" has the calibration value 12.
+///
+/// Every vocabulary includes the digit characters "1" to "9".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vocabulary {
+    tokens: Vec<(String, String)>,
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl Vocabulary {
+    /// Creates a new [`Vocabulary`] containing only the digit characters "1" to "9".
+    pub fn digits() -> Self {
+        Self {
+            tokens: (1..=9)
+                .map(|digit| (digit.to_string(), digit.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Creates a new [`Vocabulary`] containing the digit characters and the number words from
+    /// "one" to "nine".
+    pub fn english() -> Self {
+        let mut vocabulary = Self::digits();
+        vocabulary.extend(ENGLISH_WORDS);
+        vocabulary
+    }
+
+    /// Creates a new [`Vocabulary`] containing the digit characters and the number words from
+    /// "zero" to "twenty".
+    pub fn english_extended() -> Self {
+        let mut vocabulary = Self::english();
+        vocabulary.extend(ENGLISH_EXTENDED_WORDS);
+        vocabulary
+    }
+
+    /// Gets the built-in vocabulary with the given name, if there is one.
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "english" => Some(Self::english()),
+            "english-extended" => Some(Self::english_extended()),
+            _ => None,
+        }
+    }
+
+    /// Selects the vocabulary named by the `AOC_VOCAB` environment variable, or the default
+    /// vocabulary if the variable is not set.
+    pub fn from_env() -> Result<Self, ParseError> {
+        let name = env::var(VOCAB_ENV_VAR)
+            .ok()
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| DEFAULT_VOCAB.to_string());
+        Self::select(&name)
+    }
+
+    /// Selects the built-in vocabulary with the given name, otherwise loads the vocabulary from
+    /// the file with the given name.
+    pub fn select(name: &str) -> Result<Self, ParseError> {
+        match Self::builtin(name) {
+            Some(vocabulary) => Ok(vocabulary),
+            None => Self::load(name),
+        }
+    }
+
+    /// Loads the vocabulary from the given file. Relative paths are resolved from the crate root.
+    pub fn load(filename: &str) -> Result<Self, ParseError> {
+        let raw_input = input::read_input(filename)?;
+        Self::parse(filename, &raw_input)
+    }
+
+    /// Parses the contents of a vocabulary file. Each non-empty line (other than comments starting
+    /// with '#') is in the format "<token> <digits>", e.g. "zwölf 12". The digit characters "1" to
+    /// "9" are always included, and a token listed more than once uses its last digits.
+    pub fn parse(filename: &str, raw_input: &str) -> Result<Self, ParseError> {
+        let mut vocabulary = Self::digits();
+        for (i, raw_line) in raw_input.lines().enumerate() {
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            match (fields.next(), fields.next(), fields.next()) {
+                (Some(token), Some(digits), None) if digits.chars().all(|c| c.is_ascii_digit()) => {
+                    vocabulary.insert(token, digits);
+                }
+                (Some(_), Some(digits), None) => {
+                    // Columns are counted from the start of the line, including any indentation
+                    let indent = raw_line.len() - raw_line.trim_start().len();
+                    return Err(ParseError::unexpected_at(
+                        filename,
+                        i + 1,
+                        raw_line,
+                        indent + line.rfind(digits).unwrap_or_default(),
+                        "digits",
+                    ));
+                }
+                _ => {
+                    return Err(ParseError::unexpected(
+                        filename,
+                        i + 1,
+                        1,
+                        "token in format \"<token> <digits>\"",
+                    ))
+                }
+            }
+        }
+        Ok(vocabulary)
+    }
+
+    /// Gets the digits contributed by the given token, if it is in the vocabulary.
+    pub fn digits_of(&self, token: &str) -> Option<&str> {
+        self.tokens
+            .iter()
+            .find(|(t, _)| t == token)
+            .map(|(_, digits)| digits.as_str())
+    }
+
+    /// Gets the tokens in the vocabulary and the digits they contribute.
+    pub fn tokens(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.tokens
+            .iter()
+            .map(|(token, digits)| (token.as_str(), digits.as_str()))
+    }
+
+    /// Builds a scanner that finds the tokens of the vocabulary, mapped to their digits.
+    pub fn scanner(&self) -> TokenScanner<String> {
+        TokenScanner::new(
+            self.tokens
+                .iter()
+                .map(|(token, digits)| (token.as_str(), digits.clone())),
+        )
+        .expect("vocabulary too large for scanner")
+    }
+
+    /// Adds the token to the vocabulary, replacing the digits of the token if already present.
+    /// Tokens must not be empty, and digits must be one or more digit characters.
+    fn insert(&mut self, token: &str, digits: &str) {
+        debug_assert!(!token.is_empty() && !digits.is_empty());
+        match self.tokens.iter_mut().find(|(t, _)| t == token) {
+            Some((_, existing)) => *existing = digits.to_string(),
+            None => self.tokens.push((token.to_string(), digits.to_string())),
+        }
+    }
+
+    /// Adds each of the tokens to the vocabulary.
+    fn extend<'a>(&mut self, tokens: impl IntoIterator<Item = (&'a str, &'a str)>) {
+        for (token, digits) in tokens {
+            self.insert(token, digits);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the built-in vocabularies are selected by name.
+    #[test]
+    fn test_vocabulary_builtin() {
+        let english = Vocabulary::select("english").unwrap();
+        assert_eq!(Some("7"), english.digits_of("seven"));
+        assert_eq!(Some("7"), english.digits_of("7"));
+        assert_eq!(None, english.digits_of("zero"));
+        let extended = Vocabulary::select("english-extended").unwrap();
+        assert_eq!(Some("0"), extended.digits_of("zero"));
+        assert_eq!(Some("12"), extended.digits_of("twelve"));
+        assert_eq!(Vocabulary::english(), Vocabulary::default());
+    }
+
+    /// Tests that a vocabulary file is parsed, always including the digit characters.
+    #[test]
+    fn test_vocabulary_parse() {
+        let raw_input = "# French\nun 1\ndeux 2\n\nzéro 0\n0 0\ndeux 22\n";
+        let vocabulary = Vocabulary::parse("vocab.txt", raw_input).unwrap();
+        assert_eq!(Some("1"), vocabulary.digits_of("un"));
+        assert_eq!(Some("22"), vocabulary.digits_of("deux"));
+        assert_eq!(Some("0"), vocabulary.digits_of("zéro"));
+        assert_eq!(Some("0"), vocabulary.digits_of("0"));
+        assert_eq!(Some("9"), vocabulary.digits_of("9"));
+        assert_eq!(None, vocabulary.digits_of("one"));
+        assert_eq!(13, vocabulary.tokens().count());
+    }
+
+    /// Tests that vocabulary file lines in the incorrect format are reported.
+    #[test]
+    fn test_vocabulary_parse_invalid() {
+        let err = Vocabulary::parse("vocab.txt", "un 1\ndeux two\n").unwrap_err();
+        assert_eq!("vocab.txt:2:6: expected digits", err.to_string());
+        let err = Vocabulary::parse("vocab.txt", "un 1\n  \tdeux two\n").unwrap_err();
+        assert_eq!("vocab.txt:2:9: expected digits", err.to_string());
+        let err = Vocabulary::parse("vocab.txt", "un\n").unwrap_err();
+        assert_eq!(
+            "vocab.txt:1:1: expected token in format \"<token> <digits>\"",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::answers;
+use crate::examples::{EXAMPLES_DIR, EXPECTED_ANSWERS_EXTENSION};
+use crate::runner::DaySolver;
+use crate::utils::{encryption, input};
+
+/// Time between each poll of the watched files for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification times of the watched files. Files that do not exist are recorded as None, so
+/// creating or deleting a watched file also counts as a change.
+pub type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// Returns the files watched for the given day: the solver and binary source files, the problem
+/// input file and its encrypted copy, the example input and expected answers files, and the
+/// answers file.
+///
+/// Source files are located from the given crate root. The other files are resolved with
+/// [`input::resolve_input_path`], the same as when solving the problem, so relative paths are
+/// resolved from the crate root rather than the current directory.
+pub fn watched_files(solver: &DaySolver, crate_root: &Path) -> Vec<PathBuf> {
+    let day = solver.day();
+    let input_file = input::resolve_input_path(&solver.select_input_file(None));
+    let mut files = vec![
+        crate_root.join(format!("src/days/day{day:02}.rs")),
+        crate_root.join(format!("src/bin/day{day:02}.rs")),
+        encryption::encrypted_path(&input_file),
+        input_file,
+        input::resolve_input_path(&answers::answers_file()),
+    ];
+    // Example files are found again on each poll, so new examples are also picked up
+    if let Ok(entries) = fs::read_dir(input::resolve_input_path(EXAMPLES_DIR)) {
+        let prefix = format!("day{day:02}_");
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_example = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix))
+                && path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| ext == "txt" || ext == EXPECTED_ANSWERS_EXTENSION);
+            if is_example {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Records the modification time of each of the given files.
+pub fn snapshot(files: &[PathBuf]) -> Snapshot {
+    files
+        .iter()
+        .map(|file| {
+            let modified = fs::metadata(file).and_then(|meta| meta.modified()).ok();
+            (file.clone(), modified)
+        })
+        .collect()
+}
+
+/// Returns the files that were added, removed or modified between the two snapshots.
+pub fn changed_files(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let mut changed = after
+        .iter()
+        .filter(|(file, modified)| before.get(*file) != Some(*modified))
+        .map(|(file, _)| file.clone())
+        .collect::<Vec<PathBuf>>();
+    changed.extend(
+        before
+            .keys()
+            .filter(|file| !after.contains_key(*file))
+            .cloned(),
+    );
+    changed.sort();
+    changed
+}
+
+/// Watches the source and input files for the given day, rebuilding and re-checking the day
+/// against its example and problem inputs whenever one of the files changes. Runs until the
+/// process is interrupted.
+///
+/// The checks are run by `cargo run -- check <DAY>`, so changes to the solver source are compiled
+/// before checking.
+pub fn watch_day(solver: &DaySolver) {
+    let crate_root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut previous: Option<Snapshot> = None;
+    loop {
+        let current = snapshot(&watched_files(solver, crate_root));
+        let changed = match &previous {
+            Some(previous) => changed_files(previous, &current),
+            None => vec![],
+        };
+        if previous.is_none() || !changed.is_empty() {
+            println!("{}", "-".repeat(80));
+            for file in &changed {
+                println!("[*] Changed: {}", file.display());
+            }
+            run_check(solver.day(), crate_root);
+            println!("[*] Watching Day {:02} for changes...", solver.day());
+        }
+        previous = Some(current);
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Rebuilds the runner and checks the given day in a child process, so the latest solver source
+/// is used. Build errors and failed checks are reported by the child process.
+fn run_check(day: u64, crate_root: &Path) {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let status = Command::new(cargo)
+        .arg("run")
+        .arg("--quiet")
+        .arg("--release")
+        .arg("--manifest-path")
+        .arg(crate_root.join("Cargo.toml"))
+        .args(["--bin", "aoc2023", "--", "check", &day.to_string()])
+        .status();
+    if let Err(err) = status {
+        eprintln!("[!] Unable to run checks for Day {day:02} - {err}");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days;
+
+    /// Tests that the source, input and example files for the day are watched.
+    #[test]
+    fn test_watched_files() {
+        let solver = days::registry()[4];
+        let files = watched_files(&solver, Path::new(env!("CARGO_MANIFEST_DIR")));
+        for file in [
+            "./src/days/day05.rs",
+            "./src/bin/day05.rs",
+            "./input/test/day05_01.txt",
+            "./input/test/day05_01.answers",
+        ] {
+            let file = input::resolve_input_path(file);
+            assert!(files.contains(&file), "{} not watched", file.display());
+        }
+        assert!(!files.contains(&input::resolve_input_path("./input/test/day06_01.txt")));
+    }
+
+    /// Tests that added, removed and modified files are all detected as changes.
+    #[test]
+    fn test_changed_files() {
+        let time = SystemTime::UNIX_EPOCH;
+        let before = Snapshot::from([
+            (PathBuf::from("a"), Some(time)),
+            (PathBuf::from("b"), Some(time)),
+            (PathBuf::from("c"), None),
+        ]);
+        let after = Snapshot::from([
+            (PathBuf::from("a"), Some(time)),
+            (PathBuf::from("b"), Some(time + Duration::from_secs(1))),
+            (PathBuf::from("c"), Some(time)),
+            (PathBuf::from("d"), None),
+        ]);
+        assert_eq!(
+            vec![PathBuf::from("b"), PathBuf::from("c"), PathBuf::from("d")],
+            changed_files(&before, &after)
+        );
+        assert_eq!(
+            vec![PathBuf::from("b"), PathBuf::from("c"), PathBuf::from("d")],
+            changed_files(&after, &before)
+        );
+    }
+}
@@ -1,86 +1,86 @@
-use std::fs;
-use std::time::Instant;
+use crate::cancel::CancellationToken;
+use crate::utils::input;
+use crate::{Answer, ParseError, Solution};
 
 const PROBLEM_NAME: &str = "###";
 const PROBLEM_INPUT_FILE: &str = "./input/day00.txt";
 const PROBLEM_DAY: u64 = 0;
 
-/// Processes the AOC 2023 Day 00 input file and solves both parts of the problem. Solutions are
-/// printed to stdout.
-pub fn main() {
-    let start = Instant::now();
-    // Input processing
-    let input = process_input_file(PROBLEM_INPUT_FILE);
-    let input_parser_timestamp = Instant::now();
-    let input_parser_duration = input_parser_timestamp.duration_since(start);
-    // Solve part 1
-    let p1_solution = solve_part1(&input);
-    let p1_timestamp = Instant::now();
-    let p1_duration = p1_timestamp.duration_since(input_parser_timestamp);
-    // Solve part 2
-    let p2_solution = solve_part2(&input);
-    let p2_timestamp = Instant::now();
-    let p2_duration = p2_timestamp.duration_since(p1_timestamp);
-    // Print results
-    println!("==================================================");
-    println!("AOC 2023 Day {PROBLEM_DAY} - \"{PROBLEM_NAME}\"");
-    println!("[+] Part 1: {p1_solution}");
-    println!("[+] Part 2: {p2_solution}");
-    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
-    println!("Execution times:");
-    println!("[+] Input:  {input_parser_duration:.2?}");
-    println!("[+] Part 1: {p1_duration:.2?}");
-    println!("[+] Part 2: {p2_duration:.2?}");
-    println!(
-        "[*] TOTAL:  {:.2?}",
-        input_parser_duration + p1_duration + p2_duration
-    );
-    println!("==================================================");
+/// Solver for AOC 2023 Day 00.
+pub struct Day00;
+
+impl Solution for Day00 {
+    const PROBLEM_NAME: &'static str = PROBLEM_NAME;
+    const PROBLEM_INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PROBLEM_DAY: u64 = PROBLEM_DAY;
+
+    type Input = String;
+
+    fn parse(filename: &str) -> Result<Self::Input, ParseError> {
+        process_input_file(filename)
+    }
+
+    fn part1(input: &Self::Input, _token: &CancellationToken) -> Answer {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input, _token: &CancellationToken) -> Answer {
+        solve_part2(input)
+    }
 }
 
 /// Processes the AOC 2023 Day 00 input file in the format required by the solver functions.
-/// 
+///
 /// Returned value is ###.
-fn process_input_file(filename: &str) -> String {
+pub fn process_input_file(filename: &str) -> Result<String, ParseError> {
     // Read contents of problem input file
-    let _raw_input = fs::read_to_string(filename).unwrap();
+    let raw_input = input::read_input(filename)?;
     // Process input file contents into data structure
-    unimplemented!();
+    Ok(raw_input)
 }
 
 /// Solves AOC 2023 Day 00 Part 1.
-/// 
+///
 /// ###
-fn solve_part1(_input: &String) -> String {
-    unimplemented!();
+pub fn solve_part1(_input: &str) -> Answer {
+    // Placeholder answer, so the day can be registered and run before it is solved
+    Answer::from("unsolved")
 }
 
 /// Solves AOC 2023 Day 00 Part 2.
-/// 
+///
 /// ###
-fn solve_part2(_input: &String) -> String {
-    unimplemented!();
+pub fn solve_part2(_input: &str) -> Answer {
+    // Placeholder answer, so the day can be registered and run before it is solved
+    Answer::from("unsolved")
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::answers;
 
     /// Tests the Day 00 Part 1 solver method against the actual problem solution.
     #[test]
     fn test_day00_part1_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
-        let _solution = solve_part1(&input);
-        unimplemented!();
-        // assert_eq!("###", solution);
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part1(&input);
+        answers::assert_answer(PROBLEM_DAY, 1, &filename, solution);
     }
 
     /// Tests the Day 00 Part 2 solver method against the actual problem solution.
     #[test]
     fn test_day00_part2_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
-        let _solution = solve_part2(&input);
-        unimplemented!();
-        // assert_eq!("###", solution);
+        let filename = input::select_input_file(PROBLEM_DAY, PROBLEM_INPUT_FILE, None);
+        if !input::test_input_available(&filename) {
+            return;
+        }
+        let input = process_input_file(&filename).unwrap();
+        let solution = solve_part2(&input);
+        answers::assert_answer(PROBLEM_DAY, 2, &filename, solution);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,83 @@
+use aoc2023::cancel::CancellationToken;
+use aoc2023::days::{day01, day02, day03, day04, day05, day06};
+use aoc2023::vocab::Vocabulary;
+use aoc2023::Answer;
+
+/// Tests that the calibration value is extracted using scanners for the Day 01 vocabularies,
+/// including where number words overlap.
+#[test]
+fn test_day01_extract_calibration_value() {
+    let scanner = Vocabulary::english().scanner();
+    assert_eq!(
+        Some(24),
+        day01::extract_calibration_value("xtwone3four", &scanner)
+    );
+    assert_eq!(
+        Some(32),
+        day01::extract_calibration_value("3eightwo", &scanner)
+    );
+    assert_eq!(
+        None,
+        day01::extract_calibration_value("abc", &day01::DIGIT_SCANNER)
+    );
+}
+
+/// Tests the Day 02 draw checks and power calculation through the public [`day02::Draw`] and
+/// [`day02::GameCubeMax`].
+#[test]
+fn test_day02_draw_and_game_power() {
+    let draw = day02::Draw::new(4, 6, 2);
+    assert!(draw.check_draw(day02::P1_MAX_RED, day02::P1_MAX_BLUE, day02::P1_MAX_GREEN));
+    assert!(!draw.check_draw(3, 6, 2));
+    assert_eq!(48, day02::GameCubeMax::new(4, 6, 2).calculate_game_power());
+}
+
+/// Tests the public Day 03 input processing and solvers against the example input file.
+#[test]
+fn test_day03_example() {
+    let input = day03::process_input_file("./input/test/day03_01.txt").unwrap();
+    assert_eq!(Answer::Unsigned(4361), day03::solve_part1(&input));
+    assert_eq!(Answer::Unsigned(467835), day03::solve_part2(&input));
+}
+
+/// Tests the Day 04 card points calculation.
+#[test]
+fn test_day04_calculate_card_points() {
+    assert_eq!(0, day04::calculate_card_points(0));
+    assert_eq!(8, day04::calculate_card_points(4));
+}
+
+/// Tests that the public [`day05::RangeMap`] maps values and ranges covered by its source ranges.
+#[test]
+fn test_day05_range_map() {
+    let range_map = day05::RangeMap::new(vec![(50..=51, 98..=99), (52..=99, 50..=97)]);
+    assert_eq!(81, range_map.map_source_value_to_destination(79));
+    assert_eq!(10, range_map.map_source_value_to_destination(10));
+    let mut ranges = range_map.map_source_range_to_destination_range(&(45..=55));
+    ranges.sort_by_key(|range| *range.start());
+    assert_eq!(vec![45..=49, 52..=57], ranges);
+}
+
+/// Tests the public Day 05 input processing and Part 2 solver against the example input file.
+#[test]
+fn test_day05_example() {
+    let input = day05::process_input_file("./input/test/day05_01.txt").unwrap();
+    assert_eq!(
+        Answer::Unsigned(46),
+        day05::solve_part2(&input, &CancellationToken::new())
+    );
+}
+
+/// Tests the Day 06 calculation of the number of ways to beat the best distance.
+#[test]
+fn test_day06_calculate_num_ways_to_beat_best_distance() {
+    let token = CancellationToken::new();
+    assert_eq!(
+        Some(4),
+        day06::calculate_num_ways_to_beat_best_distance(7, 9, &token)
+    );
+    assert_eq!(
+        Some(71503),
+        day06::calculate_num_ways_to_beat_best_distance(71530, 940200, &token)
+    );
+}
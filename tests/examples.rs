@@ -0,0 +1,29 @@
+use aoc2023::days;
+use aoc2023::examples::{self, ExampleResult, EXAMPLES_DIR};
+
+/// Tests the solvers for each day against all of the example input files in "input/test", using
+/// the expected answers given in the ".answers" file alongside each example input file. Examples
+/// with no answers recorded yet are skipped.
+#[test]
+fn test_examples() {
+    let examples = examples::discover_examples(EXAMPLES_DIR).unwrap();
+    assert!(!examples.is_empty(), "no example input files found");
+    let registry = days::registry();
+    let results = examples
+        .iter()
+        .flat_map(|example| examples::check_example(example, &registry))
+        .collect::<Vec<ExampleResult>>();
+    for result in results.iter().filter(|result| result.is_skipped()) {
+        eprintln!("[*] {result}");
+    }
+    let failures = results
+        .iter()
+        .filter(|result| !result.is_pass() && !result.is_skipped())
+        .map(|result| result.to_string())
+        .collect::<Vec<String>>();
+    assert!(
+        failures.is_empty(),
+        "example checks failed:\n{}",
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,29 @@
+use std::env;
+use std::path::Path;
+
+use aoc2023::days;
+use aoc2023::watch;
+
+/// Tests that the input, example and answers files are still watched when run from outside the
+/// crate root. Kept in its own test binary, as it changes the current directory of the process.
+#[test]
+fn test_watched_files_outside_crate_root() {
+    let crate_root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    env::set_current_dir(env::temp_dir()).unwrap();
+    let files = watch::watched_files(&days::registry()[4], crate_root);
+    for file in [
+        "src/days/day05.rs",
+        "input/test/day05_01.txt",
+        "input/test/day05_01.answers",
+        "answers.txt",
+    ] {
+        let file = crate_root.join(file);
+        assert!(
+            files.iter().any(
+                |watched| watched.canonicalize().ok() == file.canonicalize().ok() && file.exists()
+            ),
+            "{} not watched",
+            file.display()
+        );
+    }
+}